@@ -0,0 +1,56 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! This crate checks that `hrtb-lending-iterator` compiles without the
+//! standard library. If the standard library were linked, the panic handler
+//! below would clash with that of the standard library. Run
+//! ```text
+//! cargo check --manifest-path no_std_check/Cargo.toml
+//! cargo check --manifest-path no_std_check/Cargo.toml --features alloc
+//! ```
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use hrtb_lending_iterator::*;
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+pub fn windows_mut(v: &mut [u32]) -> u32 {
+    let mut windows = v.windows_mut::<2>();
+    let mut iter = windows
+        .by_ref()
+        .take(3)
+        .take_while(|w| w[0] < 10)
+        .inspect(|_| {});
+    while let Some(w) = iter.next() {
+        w[1] += w[0];
+    }
+
+    v.windows_mut::<3>()
+        .enumerate()
+        .map(|(i, w)| i as u32 + w[0])
+        .fold(0, |a, b| a + b)
+}
+
+pub fn from_iter(v: &[u32], all: bool) -> u32 {
+    let iter = if all {
+        Either::Left(v.iter().into_lend_iter())
+    } else {
+        Either::Right(v.iter().into_lend_iter().take(1))
+    };
+    iter.copied().sum::<u32>() + v.into_into_lend_iter().into_into_iter().into_iter().count() as u32
+}
+
+#[cfg(feature = "alloc")]
+pub fn collect(v: &mut [u32]) -> alloc::vec::Vec<[u32; 2]> {
+    v.windows_mut::<2>().collect()
+}
@@ -0,0 +1,57 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{DynLendingIterator, Item, LendingIterator, LendingIteratorItem};
+use alloc::boxed::Box;
+
+/**
+
+A boxed [`DynLendingIterator`] with items of shape `S`, which is
+in turn a [`LendingIterator`].
+
+This struct is returned by [`LendingIterator::boxed`], and makes it possible
+to choose at runtime among lending iterators of different types, as long as
+their items have the same type. Due to the limitations of type inference
+with higher-rank trait bounds, the shape constructor must be specified explicitly,
+but its parameters can be usually inferred. For example,
+```rust
+use hrtb_lending_iterator::*;
+
+let mut v = [0, 1, 2, 3, 4, 5];
+let (a, b) = v.split_at_mut(3);
+let mut iters: Vec<BoxedLendingIterator<RefMutItem<[i32; 2]>>> = Vec::new();
+iters.push(a.windows_mut::<2>().boxed::<RefMutItem<_>>());
+iters.push(b.windows_mut::<2>().take(1).boxed::<RefMutItem<_>>());
+```
+
+*/
+pub struct BoxedLendingIterator<'a, S: ?Sized + for<'any> LendingIteratorItem<'any>>(
+    pub(crate) Box<dyn DynLendingIterator<S> + 'a>,
+);
+
+impl<'a, S: ?Sized + for<'any> LendingIteratorItem<'any>> BoxedLendingIterator<'a, S> {
+    pub fn new<I: DynLendingIterator<S> + 'a>(iter: I) -> Self {
+        BoxedLendingIterator(Box::new(iter))
+    }
+
+    pub fn into_inner(self) -> Box<dyn DynLendingIterator<S> + 'a> {
+        self.0
+    }
+}
+
+impl<'a, 'any, S: ?Sized + for<'b> LendingIteratorItem<'b>> LendingIteratorItem<'any>
+    for BoxedLendingIterator<'a, S>
+{
+    type Type = Item<'any, S>;
+}
+
+impl<'a, S: ?Sized + for<'any> LendingIteratorItem<'any>> LendingIterator
+    for BoxedLendingIterator<'a, S>
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        self.0.dyn_next()
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator};
+use core::ops::Deref;
+
+/// This struct is returned by [`LendingIterator::cloned`].
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct Cloned<I: LendingIterator>(pub(crate) I);
+
+impl<I: LendingIterator> Cloned<I> {
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<T: Clone, I: LendingIterator> Iterator for Cloned<I>
+where
+    for<'any> Item<'any, I>: Deref<Target = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.next().map(|x| x.deref().clone())
+    }
+}
@@ -0,0 +1,126 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator, LendingIteratorItem};
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use core::{borrow::Borrow, ops::Deref};
+
+/// This struct is returned by [`LendingIterator::dedup`].
+#[cfg(feature = "alloc")]
+pub struct Dedup<I, T: ?Sized + ToOwned> {
+    pub(crate) iter: I,
+    pub(crate) prev: Option<T::Owned>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'any, I: LendingIterator, T: ?Sized + ToOwned> LendingIteratorItem<'any> for Dedup<I, T> {
+    type Type = Item<'any, I>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I: LendingIterator, T: ?Sized + ToOwned + PartialEq> LendingIterator for Dedup<I, T>
+where
+    for<'any> Item<'any, I>: Deref<Target = T>,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        dedup_next(&mut self.iter, &mut self.prev, |prev, item| {
+            if matches!(prev.as_ref(), Some(prev) if prev.borrow() == item.deref()) {
+                false
+            } else {
+                *prev = Some(item.deref().to_owned());
+                true
+            }
+        })
+    }
+}
+
+/// This struct is returned by [`LendingIterator::dedup_by`].
+#[cfg(feature = "alloc")]
+pub struct DedupBy<I, T: ?Sized + ToOwned, F> {
+    pub(crate) iter: I,
+    pub(crate) prev: Option<T::Owned>,
+    pub(crate) same_bucket: F,
+}
+
+#[cfg(feature = "alloc")]
+impl<'any, I: LendingIterator, T: ?Sized + ToOwned, F> LendingIteratorItem<'any>
+    for DedupBy<I, T, F>
+{
+    type Type = Item<'any, I>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I: LendingIterator, T: ?Sized + ToOwned, F> LendingIterator for DedupBy<I, T, F>
+where
+    for<'any> Item<'any, I>: Deref<Target = T>,
+    F: FnMut(&T::Owned, &<I as LendingIteratorItem>::Type) -> bool,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let same_bucket = &mut self.same_bucket;
+        dedup_next(&mut self.iter, &mut self.prev, |prev, item| {
+            if matches!(prev.as_ref(), Some(prev) if same_bucket(prev, item)) {
+                false
+            } else {
+                *prev = Some(item.deref().to_owned());
+                true
+            }
+        })
+    }
+}
+
+/// This struct is returned by [`LendingIterator::dedup_by_key`].
+pub struct DedupByKey<I, K, F> {
+    pub(crate) iter: I,
+    pub(crate) prev: Option<K>,
+    pub(crate) key: F,
+}
+
+impl<'any, I: LendingIterator, K, F> LendingIteratorItem<'any> for DedupByKey<I, K, F> {
+    type Type = Item<'any, I>;
+}
+
+impl<I: LendingIterator, K: PartialEq, F> LendingIterator for DedupByKey<I, K, F>
+where
+    F: FnMut(&<I as LendingIteratorItem>::Type) -> K,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let key = &mut self.key;
+        dedup_next(&mut self.iter, &mut self.prev, |prev, item| {
+            let key = key(item);
+            if prev.as_ref() == Some(&key) {
+                false
+            } else {
+                *prev = Some(key);
+                true
+            }
+        })
+    }
+}
+
+/// The logic shared by the deduplicating adapters: `retain` decides whether
+/// an item must be returned, updating the retained copy of the previous item.
+fn dedup_next<'a, I: LendingIterator, O>(
+    iter: &'a mut I,
+    prev: &mut Option<O>,
+    mut retain: impl FnMut(&mut Option<O>, &Item<'_, I>) -> bool,
+) -> Option<Item<'a, I>> {
+    let iter: *mut I = iter;
+    loop {
+        // SAFETY: `iter` comes from the `&'a mut I` we were given, which is not
+        // used again. A rejected item is dropped at the end of this iteration,
+        // before the next reborrow, and `retain` cannot keep it, as it can only
+        // store owned values in `prev`. An accepted item is returned, so it is
+        // the only borrow of the iterator for 'a. The borrow checker rejects the
+        // safe version because the returned item keeps the iterator borrowed
+        // in the following iterations of the loop too.
+        let item = unsafe { &mut *iter }.next()?;
+        if retain(prev, &item) {
+            return Some(item);
+        }
+    }
+}
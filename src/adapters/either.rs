@@ -0,0 +1,88 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, Item, LendingIterator,
+    LendingIteratorItem,
+};
+
+/**
+
+A lending iterator that is one of two lending iterators with the same item type.
+
+This type makes it possible to return from a function, or to
+store in a variable, lending iterators of different types, provided
+that they return items of the same type for every lifetime.
+For example,
+```rust
+use hrtb_lending_iterator::*;
+
+fn windows(v: &mut [i32], all: bool) -> impl LendingIterator
+    + for<'any> LendingIteratorItem<'any, Type = &'any mut [i32; 2]> + '_ {
+    if all {
+        Either::Left(v.windows_mut::<2>())
+    } else {
+        Either::Right(v.windows_mut::<2>().take(2))
+    }
+}
+```
+
+The resulting iterator implements [`ExactSizeLendingIterator`] and
+[`DoubleEndedLendingIterator`] if both alternatives do.
+
+*/
+#[derive(Clone, Debug)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<'any, L, R> LendingIteratorItem<'any> for Either<L, R>
+where
+    L: LendingIteratorItem<'any>,
+    R: LendingIteratorItem<'any, Type = <L as LendingIteratorItem<'any>>::Type>,
+{
+    type Type = <L as LendingIteratorItem<'any>>::Type;
+}
+
+impl<L, R> LendingIterator for Either<L, R>
+where
+    L: LendingIterator,
+    R: LendingIterator + for<'any> LendingIteratorItem<'any, Type = Item<'any, L>>,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        match self {
+            Either::Left(l) => l.next(),
+            Either::Right(r) => r.next(),
+        }
+    }
+}
+
+impl<L, R> ExactSizeLendingIterator for Either<L, R>
+where
+    L: ExactSizeLendingIterator,
+    R: ExactSizeLendingIterator + for<'any> LendingIteratorItem<'any, Type = Item<'any, L>>,
+{
+    fn len(&self) -> usize {
+        match self {
+            Either::Left(l) => l.len(),
+            Either::Right(r) => r.len(),
+        }
+    }
+}
+
+impl<L, R> DoubleEndedLendingIterator for Either<L, R>
+where
+    L: DoubleEndedLendingIterator,
+    R: DoubleEndedLendingIterator + for<'any> LendingIteratorItem<'any, Type = Item<'any, L>>,
+{
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        match self {
+            Either::Left(l) => l.next_back(),
+            Either::Right(r) => r.next_back(),
+        }
+    }
+}
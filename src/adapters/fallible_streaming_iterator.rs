@@ -0,0 +1,57 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{DoubleEndedLendingIterator, Item, LendingIterator, LendingIteratorItem};
+use fallible_streaming_iterator::{
+    DoubleEndedFallibleStreamingIterator, FallibleStreamingIterator,
+};
+
+/// Converts a [`FallibleStreamingIterator`] into a fallible [`LendingIterator`],
+/// that is, a [`LendingIterator`] returning results, without allocating.
+///
+/// This function can be more conveniently accessed using the
+/// [`FallibleStreamingIteratorExt::into_lend_iter`](crate::FallibleStreamingIteratorExt::into_lend_iter) method.
+pub fn from_fallible_streaming_iter<S: FallibleStreamingIterator>(
+    iter: S,
+) -> FromFallibleStreamingIterator<S> {
+    FromFallibleStreamingIterator(iter)
+}
+
+/// A struct exposing a [`FallibleStreamingIterator`] as a [`LendingIterator`]
+/// returning items of type `Result<&'any S::Item, S::Error>`.
+///
+/// Errors are returned as items, similarly to what happens with
+/// [`std::io::Lines`]; after an error, the behavior of the iterator
+/// depends on the underlying [`FallibleStreamingIterator`].
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct FromFallibleStreamingIterator<S>(S);
+
+impl<S> FromFallibleStreamingIterator<S> {
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<'any, S: FallibleStreamingIterator> LendingIteratorItem<'any>
+    for FromFallibleStreamingIterator<S>
+{
+    type Type = Result<&'any S::Item, S::Error>;
+}
+
+impl<S: FallibleStreamingIterator> LendingIterator for FromFallibleStreamingIterator<S> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next().transpose()
+    }
+}
+
+impl<S: DoubleEndedFallibleStreamingIterator> DoubleEndedLendingIterator
+    for FromFallibleStreamingIterator<S>
+{
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next_back().transpose()
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{IntoLendingIterator, Item, LendingIterator, LendingIteratorItem};
+
+/// This struct is returned by [`LendingIterator::flat_map`].
+pub struct FlatMap<I, F, U: IntoLendingIterator> {
+    pub(crate) iter: I,
+    pub(crate) f: F,
+    pub(crate) inner: Option<U::IntoLendIter>,
+}
+
+impl<'any, I: LendingIterator, F, U: IntoLendingIterator> LendingIteratorItem<'any>
+    for FlatMap<I, F, U>
+where
+    F: FnMut(<I as LendingIteratorItem>::Type) -> U,
+{
+    type Type = Item<'any, U::IntoLendIter>;
+}
+
+impl<I, F, U> LendingIterator for FlatMap<I, F, U>
+where
+    I: LendingIterator,
+    F: FnMut(<I as LendingIteratorItem>::Type) -> U,
+    U: IntoLendingIterator,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        loop {
+            if let Some(inner) = &mut self.inner {
+                let inner: *mut U::IntoLendIter = inner;
+                // SAFETY: `inner` points to the content of `self.inner`, which we
+                // borrow mutably. If an item is lent, it is returned immediately,
+                // so it is the only borrow of `self.inner` for the rest of '_. If
+                // the inner iterator is exhausted, `None` borrows nothing, so
+                // `self.inner` can be overwritten below. The borrow checker rejects
+                // the safe version because it extends the borrow of the returned
+                // item to that assignment.
+                if let Some(item) = unsafe { &mut *inner }.next() {
+                    return Some(item);
+                }
+            }
+            self.inner = Some((self.f)(self.iter.next()?).into_lend_iter());
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use nested::Flatten;
+
+#[cfg(feature = "alloc")]
+mod nested {
+    use super::*;
+    use crate::DynLendingIterator;
+    use alloc::boxed::Box;
+    use core::marker::PhantomData;
+    use core::ptr::NonNull;
+
+    /// This struct is returned by [`LendingIterator::flatten`].
+    ///
+    /// The current inner iterator might borrow from the outer iterator, so the
+    /// latter is boxed, so that its address does not change when the adapter is
+    /// moved. The inner iterator is stored with the type it has for the lifetime
+    /// `'this`, which is never exposed: the items are returned with shape `S`.
+    pub struct Flatten<'this, I, S: ?Sized>
+    where
+        I: LendingIterator + 'this,
+        for<'any> Item<'any, I>: IntoLendingIterator,
+    {
+        inner: Option<<Item<'this, I> as IntoLendingIterator>::IntoLendIter>,
+        iter: NonNull<I>,
+        _marker: PhantomData<fn() -> S>,
+    }
+
+    impl<'this, I, S: ?Sized> Flatten<'this, I, S>
+    where
+        I: LendingIterator + 'this,
+        for<'any> Item<'any, I>: IntoLendingIterator,
+    {
+        pub(crate) fn new(iter: I) -> Self {
+            Flatten {
+                inner: None,
+                iter: NonNull::from(Box::leak(Box::new(iter))),
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<'this, I, S: ?Sized> Drop for Flatten<'this, I, S>
+    where
+        I: LendingIterator + 'this,
+        for<'any> Item<'any, I>: IntoLendingIterator,
+    {
+        fn drop(&mut self) {
+            // The inner iterator might borrow from the outer one.
+            self.inner = None;
+            // SAFETY: the pointer comes from Box::leak, and it will not be used again.
+            drop(unsafe { Box::from_raw(self.iter.as_ptr()) });
+        }
+    }
+
+    impl<'any, 'this, I, S> LendingIteratorItem<'any> for Flatten<'this, I, S>
+    where
+        I: LendingIterator + 'this,
+        for<'x> Item<'x, I>: IntoLendingIterator,
+        S: ?Sized + for<'x> LendingIteratorItem<'x>,
+    {
+        type Type = Item<'any, S>;
+    }
+
+    impl<'this, I, S> LendingIterator for Flatten<'this, I, S>
+    where
+        I: LendingIterator + 'this,
+        for<'x> Item<'x, I>: IntoLendingIterator,
+        for<'x> <Item<'x, I> as IntoLendingIterator>::IntoLendIter: DynLendingIterator<S>,
+        S: ?Sized + for<'x> LendingIteratorItem<'x>,
+    {
+        fn next(&mut self) -> Option<Item<'_, Self>> {
+            loop {
+                if let Some(inner) = &mut self.inner {
+                    let inner: *mut <Item<'this, I> as IntoLendingIterator>::IntoLendIter = inner;
+                    // SAFETY: `inner` points to the content of `self.inner`. A lent
+                    // item, turned into shape S by `dyn_next`, borrows only the inner
+                    // iterator, and it is returned at once, so nothing else touches
+                    // `self.inner` during '_. An exhausted inner iterator yields
+                    // `None`, which borrows nothing, so the inner iterator can be
+                    // dropped below before the outer one is advanced.
+                    if let Some(item) = unsafe { &mut *inner }.dyn_next() {
+                        return Some(item);
+                    }
+                }
+                self.inner = None;
+                // SAFETY: the outer iterator is owned by us and no inner iterator
+                // borrowing from it is alive. The borrow lasts until the next call
+                // to this method, as items have shape S, so they cannot depend on 'this.
+                let iter: &'this mut I = unsafe { &mut *self.iter.as_ptr() };
+                self.inner = Some(iter.next()?.into_lend_iter());
+            }
+        }
+    }
+}
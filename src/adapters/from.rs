@@ -62,6 +62,18 @@ impl<I: Iterator> LendingIterator for FromIterator<I> {
     }
 }
 
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for FromIterator<I> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedLendingIterator for FromIterator<I> {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next_back()
+    }
+}
+
 /// Converts an [`IntoIterator`] into an [`IntoLendingIterator`] without allocating.
 ///
 /// This is always possible. Note that his operation and
@@ -69,7 +81,6 @@ impl<I: Iterator> LendingIterator for FromIterator<I> {
 ///
 /// This function can be more conveniently accessed using the
 /// [`IntoIteratorExt::into_into_lend_iter`] method.
-
 pub fn from_into_iter<I: IntoIterator>(iter: I) -> FromIntoIterator<I> {
     FromIntoIterator(iter)
 }
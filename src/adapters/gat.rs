@@ -0,0 +1,60 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{GatLendingIterator, Item, LendingIterator, LendingIteratorItem};
+
+/// Converts a [`GatLendingIterator`] into a [`LendingIterator`] without allocating.
+///
+/// Note that this operation and [`LendingIterator::into_gat`] are mutually inverse.
+///
+/// This function can be more conveniently accessed using the
+/// [`GatLendingIteratorExt::into_lend_iter`](crate::GatLendingIteratorExt::into_lend_iter) method.
+pub fn from_gat<G: GatLendingIterator>(iter: G) -> FromGat<G> {
+    FromGat(iter)
+}
+
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+/// A struct exposing a [`GatLendingIterator`] as a [`LendingIterator`].
+pub struct FromGat<G>(G);
+
+impl<G> FromGat<G> {
+    pub fn into_inner(self) -> G {
+        self.0
+    }
+}
+
+impl<'any, G: GatLendingIterator> LendingIteratorItem<'any> for FromGat<G> {
+    type Type = G::Item<'any>;
+}
+
+impl<G: GatLendingIterator> LendingIterator for FromGat<G> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+/// This struct is returned by [`LendingIterator::into_gat`].
+pub struct IntoGat<I>(pub(crate) I);
+
+impl<I> IntoGat<I> {
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I: LendingIterator> GatLendingIterator for IntoGat<I> {
+    type Item<'a>
+        = Item<'a, I>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.0.next()
+    }
+}
@@ -30,9 +30,6 @@ where
     F: FnMut(&'_ <I as LendingIteratorItem>::Type),
 {
     fn next(&mut self) -> Option<Item<'_, Self>> {
-        self.iter.next().map(|item| {
-            (self.f)(&item);
-            item
-        })
+        self.iter.next().inspect(|item| (self.f)(item))
     }
 }
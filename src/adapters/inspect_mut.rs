@@ -0,0 +1,36 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator, LendingIteratorItem};
+
+/// This struct is returned by [`LendingIterator::inspect_mut`].
+#[derive(Clone, Debug)]
+pub struct InspectMut<I: LendingIterator, F>
+where
+    F: FnMut(&'_ mut <I as LendingIteratorItem>::Type),
+{
+    pub(crate) iter: I,
+    pub(crate) f: F,
+}
+
+impl<'any, I: LendingIterator, F> LendingIteratorItem<'any> for InspectMut<I, F>
+where
+    F: FnMut(&'_ mut <I as LendingIteratorItem>::Type),
+{
+    type Type = <I as LendingIteratorItem<'any>>::Type;
+}
+
+impl<I, F> LendingIterator for InspectMut<I, F>
+where
+    I: LendingIterator,
+    F: FnMut(&'_ mut <I as LendingIteratorItem>::Type),
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let mut item = self.iter.next()?;
+        (self.f)(&mut item);
+        Some(item)
+    }
+}
@@ -0,0 +1,93 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator, LendingIteratorItem};
+
+/// This struct is returned by [`LendingIterator::intersperse`].
+#[derive(Clone, Debug)]
+pub struct Intersperse<I, T> {
+    pub(crate) iter: I,
+    pub(crate) separator: T,
+    pub(crate) next_item: Option<T>,
+    pub(crate) started: bool,
+}
+
+impl<'any, I, T> LendingIteratorItem<'any> for Intersperse<I, T>
+where
+    I: LendingIterator + for<'x> LendingIteratorItem<'x, Type = T>,
+{
+    type Type = T;
+}
+
+impl<I, T: Clone> LendingIterator for Intersperse<I, T>
+where
+    I: LendingIterator + for<'x> LendingIteratorItem<'x, Type = T>,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let separator = &self.separator;
+        intersperse_next(
+            &mut self.iter,
+            &mut self.next_item,
+            &mut self.started,
+            || separator.clone(),
+        )
+    }
+}
+
+/// This struct is returned by [`LendingIterator::intersperse_with`].
+#[derive(Clone, Debug)]
+pub struct IntersperseWith<I, G, T> {
+    pub(crate) iter: I,
+    pub(crate) separator: G,
+    pub(crate) next_item: Option<T>,
+    pub(crate) started: bool,
+}
+
+impl<'any, I, G, T> LendingIteratorItem<'any> for IntersperseWith<I, G, T>
+where
+    I: LendingIterator + for<'x> LendingIteratorItem<'x, Type = T>,
+    G: FnMut() -> T,
+{
+    type Type = T;
+}
+
+impl<I, G, T> LendingIterator for IntersperseWith<I, G, T>
+where
+    I: LendingIterator + for<'x> LendingIteratorItem<'x, Type = T>,
+    G: FnMut() -> T,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        intersperse_next(
+            &mut self.iter,
+            &mut self.next_item,
+            &mut self.started,
+            &mut self.separator,
+        )
+    }
+}
+
+/// The logic shared by [`Intersperse`] and [`IntersperseWith`]: the item
+/// following a separator must be retrieved before the separator is returned,
+/// so that no separator is returned after the last item.
+fn intersperse_next<I, T>(
+    iter: &mut I,
+    next_item: &mut Option<T>,
+    started: &mut bool,
+    separator: impl FnOnce() -> T,
+) -> Option<T>
+where
+    I: LendingIterator + for<'x> LendingIteratorItem<'x, Type = T>,
+{
+    if !*started {
+        *started = true;
+        return iter.next();
+    }
+    if let Some(item) = next_item.take() {
+        return Some(item);
+    }
+    *next_item = Some(iter.next()?);
+    Some(separator())
+}
@@ -0,0 +1,117 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, Item, LendingIterator,
+    LendingIteratorItem,
+};
+use core::marker::PhantomData;
+use lender::{
+    CovariantLending, CovariantProof, DoubleEndedLender, ExactSizeLender, Lend, Lender, Lending,
+};
+
+/// This struct is returned by [`LendingIterator::into_lender`].
+///
+/// It exposes a [`LendingIterator`] as a [`Lender`] whose
+/// items have the shape `S`.
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct IntoLender<I, S: ?Sized> {
+    pub(crate) iter: I,
+    pub(crate) _marker: PhantomData<fn() -> S>,
+}
+
+impl<I, S: ?Sized> IntoLender<I, S> {
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'lend, I, S> Lending<'lend> for IntoLender<I, S>
+where
+    S: ?Sized + CovariantLending,
+    I: LendingIterator + for<'any> LendingIteratorItem<'any, Type = Lend<'any, S>>,
+{
+    type Lend = Lend<'lend, S>;
+}
+
+impl<I, S> Lender for IntoLender<I, S>
+where
+    S: ?Sized + CovariantLending,
+    I: LendingIterator + for<'any> LendingIteratorItem<'any, Type = Lend<'any, S>>,
+{
+    // The items have the shape S, so we can delegate the check to S.
+    fn __check_covariance<'long: 'short, 'short>(
+        proof: CovariantProof<<Self as Lending<'long>>::Lend>,
+    ) -> CovariantProof<<Self as Lending<'short>>::Lend> {
+        S::__check_covariance(proof)
+    }
+
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next()
+    }
+}
+
+impl<I, S> ExactSizeLender for IntoLender<I, S>
+where
+    S: ?Sized + CovariantLending,
+    I: ExactSizeLendingIterator + for<'any> LendingIteratorItem<'any, Type = Lend<'any, S>>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, S> DoubleEndedLender for IntoLender<I, S>
+where
+    S: ?Sized + CovariantLending,
+    I: DoubleEndedLendingIterator + for<'any> LendingIteratorItem<'any, Type = Lend<'any, S>>,
+{
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next_back()
+    }
+}
+
+/// Converts a [`Lender`] into a [`LendingIterator`] without allocating.
+///
+/// This function can be more conveniently accessed using the
+/// [`LenderExt::into_lend_iter`](crate::LenderExt::into_lend_iter) method.
+pub fn from_lender<L: Lender>(lender: L) -> FromLender<L> {
+    FromLender(lender)
+}
+
+/// A struct exposing a [`Lender`] as a [`LendingIterator`].
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct FromLender<L>(pub(crate) L);
+
+impl<L> FromLender<L> {
+    pub fn into_inner(self) -> L {
+        self.0
+    }
+}
+
+impl<'any, L: Lender> LendingIteratorItem<'any> for FromLender<L> {
+    type Type = Lend<'any, L>;
+}
+
+impl<L: Lender> LendingIterator for FromLender<L> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next()
+    }
+}
+
+impl<L: ExactSizeLender> ExactSizeLendingIterator for FromLender<L> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<L: DoubleEndedLender> DoubleEndedLendingIterator for FromLender<L> {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next_back()
+    }
+}
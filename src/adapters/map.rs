@@ -32,4 +32,13 @@ where
     fn next(&mut self) -> Option<Item<'_, Self>> {
         self.iter.next().map(|item| (self.map)(item))
     }
+
+    fn fold<B, G>(self, init: B, mut f: G) -> B
+    where
+        Self: Sized,
+        G: FnMut(B, Item<'_, Self>) -> B,
+    {
+        let mut map = self.map;
+        self.iter.fold(init, move |accum, item| f(accum, map(item)))
+    }
 }
@@ -0,0 +1,32 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator, LendingIteratorItem};
+
+/// This struct is returned by [`LendingIterator::map_while`].
+#[derive(Clone, Debug)]
+pub struct MapWhile<I, P> {
+    pub(crate) iter: I,
+    pub(crate) predicate: P,
+}
+
+impl<'any, I: LendingIterator, P, B> LendingIteratorItem<'any> for MapWhile<I, P>
+where
+    P: FnMut(<I as LendingIteratorItem>::Type) -> Option<B>,
+{
+    type Type = B;
+}
+
+impl<I, P, B> LendingIterator for MapWhile<I, P>
+where
+    I: LendingIterator,
+    P: FnMut(<I as LendingIteratorItem>::Type) -> Option<B>,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let item = self.iter.next()?;
+        (self.predicate)(item)
+    }
+}
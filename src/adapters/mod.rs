@@ -14,18 +14,96 @@ pub use into::IntoIter;
 mod inspect;
 pub use inspect::Inspect;
 
+mod inspect_mut;
+pub use inspect_mut::InspectMut;
+
 mod from;
 pub use from::from_into_iter;
 pub use from::from_iter;
 pub use from::FromIntoIterator;
 pub use from::FromIterator;
 
+mod flatten;
+pub use flatten::FlatMap;
+#[cfg(feature = "alloc")]
+pub use flatten::Flatten;
+
+mod gat;
+pub use gat::from_gat;
+pub use gat::FromGat;
+pub use gat::IntoGat;
+
+mod dedup;
+#[cfg(feature = "alloc")]
+pub use dedup::Dedup;
+#[cfg(feature = "alloc")]
+pub use dedup::DedupBy;
+pub use dedup::DedupByKey;
+
+mod either;
+pub use either::Either;
+
+mod intersperse;
+pub use intersperse::Intersperse;
+pub use intersperse::IntersperseWith;
+
 mod enumerate;
 pub use enumerate::Enumerate;
 
+#[cfg(feature = "lender")]
+mod lender;
+#[cfg(feature = "lender")]
+pub use self::lender::from_lender;
+#[cfg(feature = "lender")]
+pub use self::lender::FromLender;
+#[cfg(feature = "lender")]
+pub use self::lender::IntoLender;
+
+#[cfg(feature = "streaming-iterator")]
+mod streaming_iterator;
+#[cfg(feature = "streaming-iterator")]
+pub use self::streaming_iterator::from_streaming_iter;
+#[cfg(feature = "streaming-iterator")]
+pub use self::streaming_iterator::from_streaming_iter_mut;
+#[cfg(feature = "streaming-iterator")]
+pub use self::streaming_iterator::FromStreamingIterator;
+#[cfg(feature = "streaming-iterator")]
+pub use self::streaming_iterator::FromStreamingIteratorMut;
+#[cfg(all(feature = "streaming-iterator", feature = "alloc"))]
+pub use self::streaming_iterator::IntoStreamingIterator;
+#[cfg(all(feature = "streaming-iterator", feature = "alloc"))]
+pub use self::streaming_iterator::IntoStreamingIteratorMut;
+
+#[cfg(feature = "fallible-streaming-iterator")]
+mod fallible_streaming_iterator;
+#[cfg(feature = "fallible-streaming-iterator")]
+pub use self::fallible_streaming_iterator::from_fallible_streaming_iter;
+#[cfg(feature = "fallible-streaming-iterator")]
+pub use self::fallible_streaming_iterator::FromFallibleStreamingIterator;
+
 mod map;
 pub use map::Map;
 
+mod map_while;
+pub use map_while::MapWhile;
+
+mod scan;
+pub use scan::Scan;
+
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+pub use boxed::BoxedLendingIterator;
+
+mod cloned;
+pub use cloned::Cloned;
+
+mod copied;
+pub use copied::Copied;
+
+#[cfg(feature = "alloc")]
 mod to_owned_item;
+#[cfg(feature = "alloc")]
 pub use to_owned_item::ToIntoOwnedItemIterator;
+#[cfg(feature = "alloc")]
 pub use to_owned_item::ToOwnedItemIterator;
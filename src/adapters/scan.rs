@@ -0,0 +1,33 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator, LendingIteratorItem};
+
+/// This struct is returned by [`LendingIterator::scan`].
+#[derive(Clone, Debug)]
+pub struct Scan<I, St, F> {
+    pub(crate) iter: I,
+    pub(crate) state: St,
+    pub(crate) f: F,
+}
+
+impl<'any, I: LendingIterator, St, F, B> LendingIteratorItem<'any> for Scan<I, St, F>
+where
+    F: FnMut(&mut St, <I as LendingIteratorItem>::Type) -> Option<B>,
+{
+    type Type = B;
+}
+
+impl<I, St, F, B> LendingIterator for Scan<I, St, F>
+where
+    I: LendingIterator,
+    F: FnMut(&mut St, <I as LendingIteratorItem>::Type) -> Option<B>,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let item = self.iter.next()?;
+        (self.f)(&mut self.state, item)
+    }
+}
@@ -0,0 +1,234 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{DoubleEndedLendingIterator, Item, LendingIterator, LendingIteratorItem};
+use streaming_iterator::{
+    DoubleEndedStreamingIterator, DoubleEndedStreamingIteratorMut, StreamingIterator,
+    StreamingIteratorMut,
+};
+
+/// Converts a [`StreamingIterator`] into a [`LendingIterator`] returning shared
+/// references without allocating.
+///
+/// This function can be more conveniently accessed using the
+/// [`StreamingIteratorExt::into_lend_iter`](crate::StreamingIteratorExt::into_lend_iter) method.
+pub fn from_streaming_iter<S: StreamingIterator>(iter: S) -> FromStreamingIterator<S> {
+    FromStreamingIterator(iter)
+}
+
+/// A struct exposing a [`StreamingIterator`] as a [`LendingIterator`]
+/// returning items of type `&'any S::Item`.
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct FromStreamingIterator<S>(S);
+
+impl<S> FromStreamingIterator<S> {
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<'any, S: StreamingIterator> LendingIteratorItem<'any> for FromStreamingIterator<S> {
+    type Type = &'any S::Item;
+}
+
+impl<S: StreamingIterator> LendingIterator for FromStreamingIterator<S> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next()
+    }
+}
+
+impl<S: DoubleEndedStreamingIterator> DoubleEndedLendingIterator for FromStreamingIterator<S> {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next_back()
+    }
+}
+
+/// Converts a [`StreamingIteratorMut`] into a [`LendingIterator`] returning mutable
+/// references without allocating.
+///
+/// This function can be more conveniently accessed using the
+/// [`StreamingIteratorExt::into_lend_iter_mut`](crate::StreamingIteratorExt::into_lend_iter_mut) method.
+pub fn from_streaming_iter_mut<S: StreamingIteratorMut>(iter: S) -> FromStreamingIteratorMut<S> {
+    FromStreamingIteratorMut(iter)
+}
+
+/// A struct exposing a [`StreamingIteratorMut`] as a [`LendingIterator`]
+/// returning items of type `&'any mut S::Item`.
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct FromStreamingIteratorMut<S>(S);
+
+impl<S> FromStreamingIteratorMut<S> {
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<'any, S: StreamingIteratorMut> LendingIteratorItem<'any> for FromStreamingIteratorMut<S> {
+    type Type = &'any mut S::Item;
+}
+
+impl<S: StreamingIteratorMut> LendingIterator for FromStreamingIteratorMut<S> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next_mut()
+    }
+}
+
+impl<S: DoubleEndedStreamingIteratorMut> DoubleEndedLendingIterator
+    for FromStreamingIteratorMut<S>
+{
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        self.0.next_back_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use into::*;
+
+#[cfg(feature = "alloc")]
+mod into {
+    use super::*;
+    use alloc::boxed::Box;
+    use core::mem::ManuallyDrop;
+    use core::ptr::NonNull;
+
+    /// The state shared by [`IntoStreamingIterator`] and [`IntoStreamingIteratorMut`].
+    ///
+    /// A [`StreamingIterator`] must be able to return the current item
+    /// from a shared reference, so we must store the item lent by the
+    /// lending iterator; the item, however, might borrow from the iterator.
+    /// We thus box the iterator, so that its address does not change when
+    /// the adapter is moved, and store a pointer to the current item, which
+    /// remains valid until the next call to a method of the iterator.
+    struct Lent<I, T: ?Sized> {
+        iter: NonNull<I>,
+        item: Option<NonNull<T>>,
+    }
+
+    impl<I, T: ?Sized> Lent<I, T> {
+        fn new(iter: I) -> Self {
+            Lent {
+                iter: NonNull::from(Box::leak(Box::new(iter))),
+                item: None,
+            }
+        }
+
+        fn iter_mut(&mut self) -> &mut I {
+            self.item = None;
+            // SAFETY: the iterator is owned by us, and we have just
+            // discarded the only pointer to an item it might have lent.
+            unsafe { self.iter.as_mut() }
+        }
+
+        fn into_inner(self) -> I {
+            let this = ManuallyDrop::new(self);
+            // SAFETY: the pointer comes from Box::leak, and it will not be used again.
+            *unsafe { Box::from_raw(this.iter.as_ptr()) }
+        }
+    }
+
+    impl<I, T: ?Sized> Drop for Lent<I, T> {
+        fn drop(&mut self) {
+            // SAFETY: the pointer comes from Box::leak, and it will not be used again.
+            drop(unsafe { Box::from_raw(self.iter.as_ptr()) });
+        }
+    }
+
+    /// This struct is returned by [`LendingIterator::into_streaming_iter`].
+    pub struct IntoStreamingIterator<I, T: ?Sized>(Lent<I, T>);
+
+    impl<I, T: ?Sized> IntoStreamingIterator<I, T> {
+        pub(crate) fn new(iter: I) -> Self {
+            IntoStreamingIterator(Lent::new(iter))
+        }
+
+        pub fn into_inner(self) -> I {
+            self.0.into_inner()
+        }
+    }
+
+    impl<I, T: ?Sized> StreamingIterator for IntoStreamingIterator<I, T>
+    where
+        I: LendingIterator + for<'any> LendingIteratorItem<'any, Type = &'any T>,
+    {
+        type Item = T;
+
+        fn advance(&mut self) {
+            self.0.item = self.0.iter_mut().next().map(NonNull::from);
+        }
+
+        fn get(&self) -> Option<&T> {
+            // SAFETY: the item has been lent by the iterator, which
+            // has not been accessed since.
+            self.0.item.map(|item| unsafe { item.as_ref() })
+        }
+    }
+
+    impl<I, T: ?Sized> DoubleEndedStreamingIterator for IntoStreamingIterator<I, T>
+    where
+        I: DoubleEndedLendingIterator + for<'any> LendingIteratorItem<'any, Type = &'any T>,
+    {
+        fn advance_back(&mut self) {
+            self.0.item = self.0.iter_mut().next_back().map(NonNull::from);
+        }
+    }
+
+    /// This struct is returned by [`LendingIterator::into_streaming_iter_mut`].
+    pub struct IntoStreamingIteratorMut<I, T: ?Sized>(Lent<I, T>);
+
+    impl<I, T: ?Sized> IntoStreamingIteratorMut<I, T> {
+        pub(crate) fn new(iter: I) -> Self {
+            IntoStreamingIteratorMut(Lent::new(iter))
+        }
+
+        pub fn into_inner(self) -> I {
+            self.0.into_inner()
+        }
+    }
+
+    impl<I, T: ?Sized> StreamingIterator for IntoStreamingIteratorMut<I, T>
+    where
+        I: LendingIterator + for<'any> LendingIteratorItem<'any, Type = &'any mut T>,
+    {
+        type Item = T;
+
+        fn advance(&mut self) {
+            self.0.item = self.0.iter_mut().next().map(NonNull::from);
+        }
+
+        fn get(&self) -> Option<&T> {
+            // SAFETY: the item has been lent by the iterator, which
+            // has not been accessed since.
+            self.0.item.map(|item| unsafe { item.as_ref() })
+        }
+    }
+
+    impl<I, T: ?Sized> StreamingIteratorMut for IntoStreamingIteratorMut<I, T>
+    where
+        I: LendingIterator + for<'any> LendingIteratorItem<'any, Type = &'any mut T>,
+    {
+        fn get_mut(&mut self) -> Option<&mut T> {
+            // SAFETY: the item has been lent mutably by the iterator, which
+            // has not been accessed since, and we are borrowed mutably.
+            self.0.item.map(|mut item| unsafe { item.as_mut() })
+        }
+    }
+
+    impl<I, T: ?Sized> DoubleEndedStreamingIterator for IntoStreamingIteratorMut<I, T>
+    where
+        I: DoubleEndedLendingIterator + for<'any> LendingIteratorItem<'any, Type = &'any mut T>,
+    {
+        fn advance_back(&mut self) {
+            self.0.item = self.0.iter_mut().next_back().map(NonNull::from);
+        }
+    }
+
+    impl<I, T: ?Sized> DoubleEndedStreamingIteratorMut for IntoStreamingIteratorMut<I, T> where
+        I: DoubleEndedLendingIterator + for<'any> LendingIteratorItem<'any, Type = &'any mut T>
+    {
+    }
+}
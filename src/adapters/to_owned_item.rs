@@ -4,33 +4,32 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use crate::{IntoLendingIterator, LendingIterator, LendingIteratorItem};
-use std::borrow::ToOwned;
+use crate::{IntoLendingIterator, IntoOwnedItem, Item, LendingIterator};
 
 #[derive(Clone, Debug)]
-/// This struct is returned by [`LendingIterator::to_owned`].
+/// This struct is returned by [`LendingIterator::to_owned_item`].
 pub struct ToOwnedItemIterator<I: LendingIterator>(pub(crate) I);
 
-impl<Item: ?Sized + ToOwned, I: LendingIterator> Iterator for ToOwnedItemIterator<I>
+impl<Owned, I: LendingIterator> Iterator for ToOwnedItemIterator<I>
 where
-    I: for<'any> LendingIteratorItem<'any, Type = &'any Item>,
+    for<'any> Item<'any, I>: IntoOwnedItem<Owned = Owned>,
 {
-    type Item = Item::Owned;
+    type Item = Owned;
 
-    fn next(&mut self) -> Option<Item::Owned> {
-        self.0.next().map(|x| x.to_owned())
+    fn next(&mut self) -> Option<Owned> {
+        self.0.next().map(IntoOwnedItem::into_owned_item)
     }
 }
 
 #[derive(Clone, Debug)]
-/// This struct is returned by [`IntoLendingIterator::to_into_owned`].
+/// This struct is returned by [`IntoLendingIterator::to_into_owned_item`].
 pub struct ToIntoOwnedItemIterator<I: IntoLendingIterator>(pub(crate) I);
 
-impl<Item: ?Sized + ToOwned, I: IntoLendingIterator> IntoIterator for ToIntoOwnedItemIterator<I>
+impl<Owned, I: IntoLendingIterator> IntoIterator for ToIntoOwnedItemIterator<I>
 where
-    I::IntoLendIter: for<'any> LendingIteratorItem<'any, Type = &'any Item>,
+    for<'any> Item<'any, I::IntoLendIter>: IntoOwnedItem<Owned = Owned>,
 {
-    type Item = Item::Owned;
+    type Item = Owned;
     type IntoIter = ToOwnedItemIterator<I::IntoLendIter>;
 
     fn into_iter(self) -> Self::IntoIter {
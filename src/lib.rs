@@ -7,23 +7,68 @@
  */
 
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod adapters;
+#[cfg(feature = "fallible-streaming-iterator")]
+pub use self::adapters::from_fallible_streaming_iter;
+pub use self::adapters::from_gat;
 pub use self::adapters::from_into_iter;
 pub use self::adapters::from_iter;
+#[cfg(feature = "lender")]
+pub use self::adapters::from_lender;
+#[cfg(feature = "streaming-iterator")]
+pub use self::adapters::from_streaming_iter;
+#[cfg(feature = "streaming-iterator")]
+pub use self::adapters::from_streaming_iter_mut;
+#[cfg(feature = "alloc")]
+pub use self::adapters::BoxedLendingIterator;
+pub use self::adapters::Either;
 
 mod sources;
+pub use self::sources::Focus;
+#[cfg(feature = "alloc")]
+pub use self::sources::{
+    cartesian_product_lend, compositions_lend, gray_code_subsets_lend, partitions_lend,
+    permutations_lend,
+};
+
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "stream")]
+pub use self::stream::stream_from_lend_iter;
+#[cfg(feature = "stream")]
+pub use self::stream::LendingStream;
 mod traits;
 
+pub use self::traits::DoubleEndedLendingIterator;
+pub use self::traits::DynLendingIterator;
 pub use self::traits::ExactSizeLendingIterator;
+pub use self::traits::FromLendingIterator;
+pub use self::traits::GatLendingIterator;
 pub use self::traits::IntoLendingIterator;
+#[cfg(feature = "alloc")]
+pub use self::traits::IntoOwnedItem;
 pub use self::traits::Item;
 pub use self::traits::LendingIterator;
 pub use self::traits::LendingIteratorItem;
+pub use self::traits::OwnedItem;
+pub use self::traits::RefItem;
+pub use self::traits::RefMutItem;
 
+#[cfg(feature = "fallible-streaming-iterator")]
+pub use self::traits::FallibleStreamingIteratorExt;
+pub use self::traits::GatLendingIteratorExt;
 pub use self::traits::IntoIteratorExt;
 pub use self::traits::IteratorExt;
+#[cfg(feature = "lender")]
+pub use self::traits::LenderExt;
 pub use self::traits::SliceExt;
+#[cfg(feature = "streaming-iterator")]
+pub use self::traits::StreamingIteratorExt;
 
 /// A macro to iterate easily over an [`IntoLendingIterator`].
 ///
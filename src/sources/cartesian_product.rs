@@ -0,0 +1,150 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, Item, LendingIterator,
+    LendingIteratorItem,
+};
+use alloc::vec::Vec;
+
+/// Returns a lending iterator on the cartesian product of the given slices (the *axes*).
+///
+/// The iterator returns, in odometer order (i.e., the last axis varies
+/// fastest), slices containing one element for each axis. The slices are
+/// obtained from a buffer that is reused across items, and only the elements
+/// that change are copied. The iterator implements [`ExactSizeLendingIterator`]
+/// and [`DoubleEndedLendingIterator`].
+///
+/// If there are no axes, the only item is the empty slice.
+///
+/// ```rust
+/// use hrtb_lending_iterator::*;
+///
+/// let mut iter = cartesian_product_lend(&[&[0, 1], &[2, 3, 4]]);
+/// assert_eq!(iter.len(), 6);
+/// assert_eq!(iter.next(), Some(&[0, 2][..]));
+/// assert_eq!(iter.next(), Some(&[0, 3][..]));
+/// assert_eq!(iter.next_back(), Some(&[1, 4][..]));
+/// ```
+///
+/// This function is available only if the `alloc` feature is enabled.
+pub fn cartesian_product_lend<'a, T: Clone>(axes: &'a [&'a [T]]) -> CartesianProduct<'a, T> {
+    let done = axes.iter().any(|axis| axis.is_empty());
+    let buffer = if done {
+        Vec::new()
+    } else {
+        axes.iter().map(|axis| axis[0].clone()).collect()
+    };
+    CartesianProduct {
+        axes,
+        front: alloc::vec![0; axes.len()],
+        back: axes
+            .iter()
+            .map(|axis| axis.len().saturating_sub(1))
+            .collect(),
+        current: alloc::vec![0; axes.len()],
+        buffer,
+        done,
+    }
+}
+
+/// This struct is returned by [`cartesian_product_lend`].
+pub struct CartesianProduct<'a, T> {
+    axes: &'a [&'a [T]],
+    /// The indices of the next item from the front.
+    front: Vec<usize>,
+    /// The indices of the next item from the back.
+    back: Vec<usize>,
+    /// The indices of the elements currently in the buffer.
+    current: Vec<usize>,
+    buffer: Vec<T>,
+    done: bool,
+}
+
+impl<'a, T: Clone> CartesianProduct<'a, T> {
+    /// Copies into the buffer the elements with the given indices that
+    /// are not already there.
+    fn load(&mut self, from_back: bool) {
+        let indices = if from_back { &self.back } else { &self.front };
+        for (j, &index) in indices.iter().enumerate() {
+            if self.current[j] != index {
+                self.buffer[j].clone_from(&self.axes[j][index]);
+                self.current[j] = index;
+            }
+        }
+    }
+}
+
+impl<'a, 'any, T> LendingIteratorItem<'any> for CartesianProduct<'a, T> {
+    type Type = &'any [T];
+}
+
+impl<'a, T: Clone> LendingIterator for CartesianProduct<'a, T> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        self.load(false);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            for (index, axis) in self.front.iter_mut().zip(self.axes).rev() {
+                *index += 1;
+                if *index < axis.len() {
+                    break;
+                }
+                *index = 0;
+            }
+        }
+        Some(&self.buffer)
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedLendingIterator for CartesianProduct<'a, T> {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        self.load(true);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            for (index, axis) in self.back.iter_mut().zip(self.axes).rev() {
+                if *index > 0 {
+                    *index -= 1;
+                    break;
+                }
+                *index = axis.len() - 1;
+            }
+        }
+        Some(&self.buffer)
+    }
+}
+
+impl<'a, T: Clone> ExactSizeLendingIterator for CartesianProduct<'a, T> {
+    /// Returns the number of remaining items.
+    ///
+    /// # Panics
+    ///
+    /// If the number of remaining items does not fit a `usize`.
+    fn len(&self) -> usize {
+        if self.done {
+            return 0;
+        }
+        let rank = |indices: &[usize]| {
+            indices
+                .iter()
+                .zip(self.axes)
+                .try_fold(0_usize, |rank, (&index, axis)| {
+                    rank.checked_mul(axis.len())?.checked_add(index)
+                })
+        };
+        rank(&self.back)
+            .zip(rank(&self.front))
+            .and_then(|(back, front)| (back - front).checked_add(1))
+            .expect("the number of items does not fit a usize")
+    }
+}
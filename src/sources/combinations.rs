@@ -0,0 +1,102 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator, LendingIteratorItem};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// This struct is returned by [`SliceExt::combinations_lend`](crate::SliceExt::combinations_lend)
+/// and [`SliceExt::combinations_with_replacement_lend`](crate::SliceExt::combinations_with_replacement_lend).
+///
+/// Combinations are generated in lexicographic order of their indices; only
+/// the values whose indices change are copied into the value buffer.
+pub struct Combinations<'a, T> {
+    slice: &'a [T],
+    indices: Vec<usize>,
+    values: Vec<T>,
+    with_replacement: bool,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T: Clone> Combinations<'a, T> {
+    pub(crate) fn new(slice: &'a [T], k: usize, with_replacement: bool) -> Self {
+        let indices: Vec<usize> = if with_replacement {
+            vec![0; k]
+        } else {
+            (0..k).collect()
+        };
+        let done = if with_replacement {
+            k > 0 && slice.is_empty()
+        } else {
+            k > slice.len()
+        };
+        let values = if done {
+            Vec::new()
+        } else {
+            indices.iter().map(|&i| slice[i].clone()).collect()
+        };
+        Combinations {
+            slice,
+            indices,
+            values,
+            with_replacement,
+            started: false,
+            done,
+        }
+    }
+
+    /// Returns the indices in the slice of the elements of the current combination.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Advances the indices to the next combination, returning the
+    /// position of the first changed index, or `None` if there are no more
+    /// combinations.
+    fn advance(&mut self) -> Option<usize> {
+        let n = self.slice.len();
+        let k = self.indices.len();
+        let i = if self.with_replacement {
+            self.indices.iter().rposition(|&index| index < n - 1)?
+        } else {
+            (0..k).rev().find(|&i| self.indices[i] < n - k + i)?
+        };
+        self.indices[i] += 1;
+        for j in i + 1..k {
+            self.indices[j] = if self.with_replacement {
+                self.indices[i]
+            } else {
+                self.indices[j - 1] + 1
+            };
+        }
+        Some(i)
+    }
+}
+
+impl<'a, 'any, T> LendingIteratorItem<'any> for Combinations<'a, T> {
+    type Type = &'any [T];
+}
+
+impl<'a, T: Clone> LendingIterator for Combinations<'a, T> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+        } else {
+            let Some(first_changed) = self.advance() else {
+                self.done = true;
+                return None;
+            };
+            for j in first_changed..self.indices.len() {
+                self.values[j].clone_from(&self.slice[self.indices[j]]);
+            }
+        }
+        Some(&self.values)
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, Item, LendingIterator,
+    LendingIteratorItem,
+};
+
+/// The item returned by [`SliceExt::focus_mut`](crate::SliceExt::focus_mut): a
+/// mutable reference to an element of a slice, together with mutable references
+/// to the parts of the slice on its left and on its right.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Focus<'a, T> {
+    /// The elements before the focus.
+    pub left: &'a mut [T],
+    /// The element in focus.
+    pub focus: &'a mut T,
+    /// The elements after the focus.
+    pub right: &'a mut [T],
+}
+
+/// This struct is returned by [`SliceExt::focus_mut`](crate::SliceExt::focus_mut).
+pub struct FocusMut<'a, T> {
+    pub(crate) slice: &'a mut [T],
+    /// The index of the next focus from the front.
+    pub(crate) front: usize,
+    /// One plus the index of the next focus from the back.
+    pub(crate) back: usize,
+}
+
+impl<'a, T> FocusMut<'a, T> {
+    fn focus(&mut self, i: usize) -> Focus<'_, T> {
+        let (left, rest) = self.slice.split_at_mut(i);
+        let (focus, right) = rest.split_first_mut().unwrap();
+        Focus { left, focus, right }
+    }
+}
+
+impl<'a, 'any, T> LendingIteratorItem<'any> for FocusMut<'a, T> {
+    type Type = Focus<'any, T>;
+}
+
+impl<'a, T> LendingIterator for FocusMut<'a, T> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.focus(self.front - 1))
+    }
+}
+
+impl<'a, T> DoubleEndedLendingIterator for FocusMut<'a, T> {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.focus(self.back))
+    }
+}
+
+impl<'a, T> ExactSizeLendingIterator for FocusMut<'a, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
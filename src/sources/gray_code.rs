@@ -0,0 +1,102 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{ExactSizeLendingIterator, Item, LendingIterator, LendingIteratorItem};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Returns a lending iterator on the subsets of a set of `n` elements in
+/// Gray-code order.
+///
+/// Each subset is returned as a slice of `n` booleans, its characteristic
+/// vector. The first subset is the empty one, and each subset is obtained from
+/// the previous one by flipping a single element, whose index is returned
+/// by the `flipped` method of the iterator.
+///
+/// ```rust
+/// use hrtb_lending_iterator::*;
+///
+/// let mut iter = gray_code_subsets_lend(2);
+/// assert_eq!(iter.len(), 4);
+/// assert_eq!(iter.next(), Some(&[false, false][..]));
+/// assert_eq!(iter.next(), Some(&[true, false][..]));
+/// assert_eq!(iter.flipped(), Some(0));
+/// assert_eq!(iter.next(), Some(&[true, true][..]));
+/// assert_eq!(iter.flipped(), Some(1));
+/// assert_eq!(iter.next(), Some(&[false, true][..]));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// This function is available only if the `alloc` feature is enabled.
+pub fn gray_code_subsets_lend(n: usize) -> GrayCodeSubsets {
+    GrayCodeSubsets {
+        subset: vec![false; n],
+        step: 0,
+        flipped: None,
+        done: false,
+    }
+}
+
+/// This struct is returned by [`gray_code_subsets_lend`].
+pub struct GrayCodeSubsets {
+    subset: Vec<bool>,
+    /// The number of subsets returned so far.
+    step: usize,
+    flipped: Option<usize>,
+    done: bool,
+}
+
+impl GrayCodeSubsets {
+    /// Returns the index of the element flipped to obtain the last subset
+    /// returned, or `None` if no subset has been returned yet or the last
+    /// subset returned is the first one.
+    pub fn flipped(&self) -> Option<usize> {
+        self.flipped
+    }
+}
+
+impl<'any> LendingIteratorItem<'any> for GrayCodeSubsets {
+    type Type = &'any [bool];
+}
+
+impl LendingIterator for GrayCodeSubsets {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        if self.step > 0 {
+            // The element to flip at step t is the number of trailing zeros of t.
+            let i = self.step.trailing_zeros() as usize;
+            if i >= self.subset.len() {
+                self.done = true;
+                return None;
+            }
+            self.subset[i] = !self.subset[i];
+            self.flipped = Some(i);
+        }
+        self.step += 1;
+        Some(&self.subset)
+    }
+}
+
+impl ExactSizeLendingIterator for GrayCodeSubsets {
+    /// Returns the number of remaining subsets.
+    ///
+    /// # Panics
+    ///
+    /// If the number of subsets does not fit a `usize`.
+    fn len(&self) -> usize {
+        if self.done {
+            return 0;
+        }
+        let n = self.subset.len();
+        assert!(
+            n < usize::BITS as usize,
+            "the number of subsets does not fit a usize"
+        );
+        (1 << n) - self.step
+    }
+}
@@ -6,3 +6,37 @@
 
 mod windows_mut;
 pub use windows_mut::WindowsMut;
+
+mod focus_mut;
+pub use focus_mut::{Focus, FocusMut};
+
+mod pairs_mut;
+pub use pairs_mut::PairsMut;
+
+mod slice_iter;
+pub use slice_iter::{SliceIter, SliceIterMut};
+
+#[cfg(feature = "alloc")]
+mod permutations;
+#[cfg(feature = "alloc")]
+pub use permutations::{permutations_lend, Permutations};
+
+#[cfg(feature = "alloc")]
+mod combinations;
+#[cfg(feature = "alloc")]
+pub use combinations::Combinations;
+
+#[cfg(feature = "alloc")]
+mod cartesian_product;
+#[cfg(feature = "alloc")]
+pub use cartesian_product::cartesian_product_lend;
+
+#[cfg(feature = "alloc")]
+mod partitions;
+#[cfg(feature = "alloc")]
+pub use partitions::{compositions_lend, partitions_lend};
+
+#[cfg(feature = "alloc")]
+mod gray_code;
+#[cfg(feature = "alloc")]
+pub use gray_code::gray_code_subsets_lend;
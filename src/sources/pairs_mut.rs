@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{ExactSizeLendingIterator, Item, LendingIterator, LendingIteratorItem};
+
+/// This struct is returned by [`SliceExt::pairs_mut`](crate::SliceExt::pairs_mut)
+/// and [`SliceExt::ordered_pairs_mut`](crate::SliceExt::ordered_pairs_mut).
+pub struct PairsMut<'a, T> {
+    pub(crate) slice: &'a mut [T],
+    /// The index of the first element of the next pair.
+    pub(crate) i: usize,
+    /// The index of the second element of the next pair.
+    pub(crate) j: usize,
+    /// Whether the pairs with `i > j` are returned, too.
+    pub(crate) ordered: bool,
+}
+
+impl<'a, T> PairsMut<'a, T> {
+    pub(crate) fn new(slice: &'a mut [T], ordered: bool) -> Self {
+        PairsMut {
+            slice,
+            i: 0,
+            j: 1,
+            ordered,
+        }
+    }
+}
+
+impl<'a, 'any, T> LendingIteratorItem<'any> for PairsMut<'a, T> {
+    type Type = (&'any mut T, &'any mut T);
+}
+
+impl<'a, T> LendingIterator for PairsMut<'a, T> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let n = self.slice.len();
+        if self.i >= n || self.j >= n {
+            return None;
+        }
+        let (i, j) = (self.i, self.j);
+        self.j += 1;
+        if self.j >= n {
+            self.i += 1;
+            self.j = if self.ordered { 0 } else { self.i + 1 };
+        }
+        if self.ordered && self.j == self.i {
+            self.j += 1;
+        }
+        if i < j {
+            let (left, right) = self.slice.split_at_mut(j);
+            Some((&mut left[i], &mut right[0]))
+        } else {
+            let (left, right) = self.slice.split_at_mut(i);
+            Some((&mut right[0], &mut left[j]))
+        }
+    }
+}
+
+impl<'a, T> ExactSizeLendingIterator for PairsMut<'a, T> {
+    fn len(&self) -> usize {
+        let n = self.slice.len();
+        if self.i >= n || self.j >= n {
+            return 0;
+        }
+        // The pairs with first index i, plus the pairs with a larger first index
+        let m = n - self.i - 1;
+        if self.ordered {
+            n - self.j - usize::from(self.i > self.j) + m * (n - 1)
+        } else {
+            n - self.j + m * m.saturating_sub(1) / 2
+        }
+    }
+}
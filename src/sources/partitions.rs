@@ -0,0 +1,166 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{ExactSizeLendingIterator, Item, LendingIterator, LendingIteratorItem};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Returns a lending iterator on the partitions of `n`.
+///
+/// Each partition is returned as a slice of positive parts in descending
+/// order, and partitions are returned in reverse lexicographic order, starting
+/// from `[n]` and ending with `n` ones. The only partition of zero is the empty
+/// slice. Each partition is obtained by modifying in place the previous one.
+///
+/// ```rust
+/// use hrtb_lending_iterator::*;
+///
+/// let mut iter = partitions_lend(4);
+/// assert_eq!(iter.next(), Some(&[4][..]));
+/// assert_eq!(iter.next(), Some(&[3, 1][..]));
+/// assert_eq!(iter.next(), Some(&[2, 2][..]));
+/// assert_eq!(iter.next(), Some(&[2, 1, 1][..]));
+/// assert_eq!(iter.next(), Some(&[1, 1, 1, 1][..]));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// This function is available only if the `alloc` feature is enabled.
+pub fn partitions_lend(n: usize) -> Partitions {
+    Partitions {
+        parts: if n == 0 { Vec::new() } else { vec![n] },
+        started: false,
+    }
+}
+
+/// This struct is returned by [`partitions_lend`].
+pub struct Partitions {
+    parts: Vec<usize>,
+    started: bool,
+}
+
+impl<'any> LendingIteratorItem<'any> for Partitions {
+    type Type = &'any [usize];
+}
+
+impl LendingIterator for Partitions {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if !self.started {
+            self.started = true;
+            return Some(&self.parts);
+        }
+        // The rightmost part greater than one; the following parts are ones.
+        let h = self.parts.iter().rposition(|&part| part > 1)?;
+        let part = self.parts[h] - 1;
+        let mut rest = self.parts.len() - h;
+        self.parts.truncate(h);
+        self.parts.push(part);
+        while rest >= part {
+            self.parts.push(part);
+            rest -= part;
+        }
+        if rest > 0 {
+            self.parts.push(rest);
+        }
+        Some(&self.parts)
+    }
+}
+
+/// Returns a lending iterator on the compositions of `n` into `k` parts.
+///
+/// Each composition is returned as a slice of `k` positive parts summing to `n`,
+/// and compositions are returned in lexicographic order. The only composition
+/// of zero into zero parts is the empty slice, and there are no compositions
+/// if `k` is zero and `n` is not, or if `k` is greater than `n`. Each
+/// composition is obtained by modifying in place the previous one.
+///
+/// ```rust
+/// use hrtb_lending_iterator::*;
+///
+/// let mut iter = compositions_lend(4, 2);
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.next(), Some(&[1, 3][..]));
+/// assert_eq!(iter.next(), Some(&[2, 2][..]));
+/// assert_eq!(iter.next(), Some(&[3, 1][..]));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// This function is available only if the `alloc` feature is enabled.
+pub fn compositions_lend(n: usize, k: usize) -> Compositions {
+    let mut parts = vec![1; k];
+    let remaining = if k == 0 {
+        Some(usize::from(n == 0))
+    } else if k > n {
+        Some(0)
+    } else {
+        parts[k - 1] = n - k + 1;
+        // The number of compositions is the binomial coefficient (n - 1 choose k - 1).
+        // Using the smaller of k - 1 and n - k, the partial results are increasing,
+        // and each product fits a u128 if the previous partial result fits a usize.
+        let (m, j) = (n - 1, (k - 1).min(n - k));
+        (0..j).try_fold(1_usize, |c, i| {
+            usize::try_from(c as u128 * (m - i) as u128 / (i + 1) as u128).ok()
+        })
+    };
+    Compositions {
+        parts,
+        started: false,
+        done: remaining == Some(0),
+        remaining,
+    }
+}
+
+/// This struct is returned by [`compositions_lend`].
+pub struct Compositions {
+    parts: Vec<usize>,
+    started: bool,
+    done: bool,
+    /// The number of compositions still to be returned, if it fits a `usize`.
+    remaining: Option<usize>,
+}
+
+impl<'any> LendingIteratorItem<'any> for Compositions {
+    type Type = &'any [usize];
+}
+
+impl LendingIterator for Compositions {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+        } else {
+            // The rightmost part greater than one; the following parts are ones.
+            match self.parts.iter().rposition(|&part| part > 1) {
+                Some(m) if m > 0 => {
+                    let part = self.parts[m];
+                    let k = self.parts.len();
+                    self.parts[m - 1] += 1;
+                    self.parts[m] = 1;
+                    self.parts[k - 1] = part - 1;
+                }
+                _ => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+        self.remaining = self.remaining.map(|r| r - 1);
+        Some(&self.parts)
+    }
+}
+
+impl ExactSizeLendingIterator for Compositions {
+    /// Returns the number of remaining compositions.
+    ///
+    /// # Panics
+    ///
+    /// If the number of compositions does not fit a `usize`.
+    fn len(&self) -> usize {
+        self.remaining
+            .expect("the number of compositions does not fit a usize")
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{ExactSizeLendingIterator, Item, LendingIterator, LendingIteratorItem};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+/// Returns a lending iterator on all permutations of the elements of an owned
+/// buffer, such as a [`Vec`] or a boxed slice, which are generated in place.
+///
+/// This function is the owned counterpart of
+/// [`SliceExt::permutations_lend`](crate::SliceExt::permutations_lend), and the
+/// permutations are returned in the same order; at the end of the iteration,
+/// the buffer can be retrieved using the `into_inner` method of the returned
+/// iterator.
+///
+/// ```rust
+/// use hrtb_lending_iterator::*;
+///
+/// let mut iter = permutations_lend(vec![0, 1]);
+/// assert_eq!(iter.next(), Some(&[0, 1][..]));
+/// assert_eq!(iter.next(), Some(&[1, 0][..]));
+/// assert_eq!(iter.next(), None);
+/// assert_eq!(iter.into_inner(), [1, 0]);
+/// ```
+///
+/// This function is available only if the `alloc` feature is enabled.
+pub fn permutations_lend<T, B: DerefMut<Target = [T]>>(buffer: B) -> Permutations<B> {
+    Permutations::new(buffer)
+}
+
+/// This struct is returned by [`SliceExt::permutations_lend`](crate::SliceExt::permutations_lend)
+/// and [`permutations_lend`].
+///
+/// Permutations are generated in place using Heap's algorithm, so each
+/// permutation is obtained from the previous one by a single swap.
+pub struct Permutations<B> {
+    buffer: B,
+    /// The loop counters of the iterative version of Heap's algorithm.
+    counters: Vec<usize>,
+    /// The index of the counter being examined.
+    i: usize,
+    started: bool,
+    /// The number of permutations still to be returned, if it fits a `usize`.
+    remaining: Option<usize>,
+}
+
+impl<T, B: DerefMut<Target = [T]>> Permutations<B> {
+    pub(crate) fn new(buffer: B) -> Self {
+        let remaining = (1..=buffer.len()).try_fold(1_usize, usize::checked_mul);
+        Permutations {
+            counters: vec![0; buffer.len()],
+            buffer,
+            i: 1,
+            started: false,
+            remaining,
+        }
+    }
+
+    /// Returns the buffer, which contains the last permutation returned.
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<'any, B: Deref> LendingIteratorItem<'any> for Permutations<B> {
+    type Type = &'any B::Target;
+}
+
+impl<T, B: DerefMut<Target = [T]>> LendingIterator for Permutations<B> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if !self.started {
+            self.started = true;
+        } else {
+            loop {
+                let i = self.i;
+                if i >= self.buffer.len() {
+                    return None;
+                }
+                if self.counters[i] < i {
+                    if i % 2 == 0 {
+                        self.buffer.swap(0, i);
+                    } else {
+                        self.buffer.swap(self.counters[i], i);
+                    }
+                    self.counters[i] += 1;
+                    self.i = 1;
+                    break;
+                }
+                self.counters[i] = 0;
+                self.i += 1;
+            }
+        }
+        self.remaining = self.remaining.map(|r| r - 1);
+        Some(&self.buffer)
+    }
+}
+
+impl<T, B: DerefMut<Target = [T]>> ExactSizeLendingIterator for Permutations<B> {
+    /// Returns the number of remaining permutations.
+    ///
+    /// # Panics
+    ///
+    /// If the number of permutations of the buffer does not fit a `usize`
+    /// (i.e., if the buffer has more than 20 elements on 64-bit platforms).
+    fn len(&self) -> usize {
+        self.remaining
+            .expect("the number of permutations does not fit a usize")
+    }
+}
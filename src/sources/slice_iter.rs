@@ -0,0 +1,80 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, Item, LendingIterator,
+    LendingIteratorItem,
+};
+
+/// The lending iterator on a slice returned by
+/// [`IntoLendingIterator::into_lend_iter`](crate::IntoLendingIterator::into_lend_iter)
+/// on references to slices, arrays and vectors.
+///
+/// Differently from the iterators obtained from
+/// [`IteratorExt::into_lend_iter`](crate::IteratorExt::into_lend_iter),
+/// the items are reborrowed for the lifetime of the iterator borrow, so they
+/// have shape [`RefItem<T>`](crate::RefItem).
+pub struct SliceIter<'a, T> {
+    pub(crate) slice: &'a [T],
+}
+
+impl<'a, 'any, T> LendingIteratorItem<'any> for SliceIter<'a, T> {
+    type Type = &'any T;
+}
+
+impl<'a, T> LendingIterator for SliceIter<'a, T> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let (first, rest) = self.slice.split_first()?;
+        self.slice = rest;
+        Some(first)
+    }
+}
+
+impl<'a, T> DoubleEndedLendingIterator for SliceIter<'a, T> {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        let (last, rest) = self.slice.split_last()?;
+        self.slice = rest;
+        Some(last)
+    }
+}
+
+impl<'a, T> ExactSizeLendingIterator for SliceIter<'a, T> {
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+}
+
+/// Like [`SliceIter`], but for mutable references; the items have
+/// shape [`RefMutItem<T>`](crate::RefMutItem).
+pub struct SliceIterMut<'a, T> {
+    pub(crate) slice: &'a mut [T],
+}
+
+impl<'a, 'any, T> LendingIteratorItem<'any> for SliceIterMut<'a, T> {
+    type Type = &'any mut T;
+}
+
+impl<'a, T> LendingIterator for SliceIterMut<'a, T> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let (first, rest) = core::mem::take(&mut self.slice).split_first_mut()?;
+        self.slice = rest;
+        Some(first)
+    }
+}
+
+impl<'a, T> DoubleEndedLendingIterator for SliceIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        let (last, rest) = core::mem::take(&mut self.slice).split_last_mut()?;
+        self.slice = rest;
+        Some(last)
+    }
+}
+
+impl<'a, T> ExactSizeLendingIterator for SliceIterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+}
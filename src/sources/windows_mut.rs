@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use crate::{Item, LendingIterator, LendingIteratorItem};
+use crate::{ExactSizeLendingIterator, Item, LendingIterator, LendingIteratorItem};
 
 /// This struct is returned by [`IteratorExt::windows_mut`].
 pub struct WindowsMut<'a, T, const WINDOW_SIZE: usize> {
@@ -29,3 +29,9 @@ impl<'a, T, const WINDOW_SIZE: usize> LendingIterator for WindowsMut<'a, T, WIND
         Some(window.try_into().unwrap())
     }
 }
+
+impl<'a, T, const WINDOW_SIZE: usize> ExactSizeLendingIterator for WindowsMut<'a, T, WINDOW_SIZE> {
+    fn len(&self) -> usize {
+        (self.slice.len() + 1).saturating_sub(self.curr_pos + WINDOW_SIZE)
+    }
+}
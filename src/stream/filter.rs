@@ -0,0 +1,60 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIteratorItem, LendingStream};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// This struct is returned by [`LendingStream::filter`].
+#[derive(Clone, Debug)]
+pub struct Filter<S: LendingStream, P>
+where
+    P: FnMut(&'_ <S as LendingIteratorItem>::Type) -> bool,
+{
+    pub(crate) stream: S,
+    pub(crate) predicate: P,
+}
+
+impl<S: LendingStream + Unpin, P> Unpin for Filter<S, P> where
+    P: FnMut(&'_ <S as LendingIteratorItem>::Type) -> bool
+{
+}
+
+impl<'any, S: LendingStream, P> LendingIteratorItem<'any> for Filter<S, P>
+where
+    P: FnMut(&'_ <S as LendingIteratorItem>::Type) -> bool,
+{
+    type Type = <S as LendingIteratorItem<'any>>::Type;
+}
+
+impl<S, P> LendingStream for Filter<S, P>
+where
+    S: LendingStream + Unpin,
+    P: FnMut(&'_ <S as LendingIteratorItem>::Type) -> bool,
+{
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Item<'_, Self>>> {
+        let this = self.get_mut();
+        let stream: *mut S = &mut this.stream;
+        loop {
+            // SAFETY: `stream` points to `this.stream`, which is valid for the
+            // whole call. The reborrow is used only to poll, and the item it
+            // yields is the only live borrow of the stream: `predicate` is a
+            // distinct field, and a rejected item is dropped at the end of this
+            // iteration, before the next reborrow. An accepted item is returned,
+            // and the stream is not touched again. The borrow checker rejects the
+            // safe version because the returned item keeps `this.stream` borrowed
+            // in the following iterations of the loop too.
+            match Pin::new(unsafe { &mut *stream }).poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if (this.predicate)(&item) {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                poll => return poll,
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{IntoLendingIterator, Item, LendingIterator, LendingIteratorItem, LendingStream};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Converts an [`IntoLendingIterator`] into a [`LendingStream`] which is always ready.
+///
+/// This function is available only if the `stream` feature is enabled.
+pub fn stream_from_lend_iter<I: IntoLendingIterator>(iter: I) -> LendIterStream<I::IntoLendIter> {
+    LendIterStream(iter.into_lend_iter())
+}
+
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+/// A struct exposing a [`LendingIterator`] as a [`LendingStream`].
+pub struct LendIterStream<I: LendingIterator>(I);
+
+impl<I: LendingIterator> LendIterStream<I> {
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+// The iterator is never pinned.
+impl<I: LendingIterator> Unpin for LendIterStream<I> {}
+
+impl<'any, I: LendingIterator> LendingIteratorItem<'any> for LendIterStream<I> {
+    type Type = <I as LendingIteratorItem<'any>>::Type;
+}
+
+impl<I: LendingIterator> LendingStream for LendIterStream<I> {
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Item<'_, Self>>> {
+        Poll::Ready(self.get_mut().0.next())
+    }
+}
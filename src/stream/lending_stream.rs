@@ -0,0 +1,114 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{Filter, Map, Take};
+use crate::{Item, LendingIteratorItem};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/**
+
+An asynchronous version of [`LendingIterator`](crate::LendingIterator).
+
+It plays the same role of `Stream` in the [futures](https://crates.io/crates/futures)
+crate for [`Iterator`]: values are produced asynchronously by
+[`poll_next`](LendingStream::poll_next), but, as in the case of a
+[`LendingIterator`](crate::LendingIterator), they are lent from the stream,
+and thus invalidated by the next call to [`poll_next`](LendingStream::poll_next).
+The type of the items is specified by [`LendingIteratorItem`], exactly as
+in the case of lending iterators.
+
+Items can be awaited using [`LendingStream::next`]:
+```ignore
+while let Some(item) = stream.next().await {
+    // Do something with item
+}
+```
+
+Adapters require the underlying stream to be [`Unpin`]; streams
+that are not can be pinned using [`Box::pin`].
+
+This trait is available only if the `stream` feature is enabled.
+
+*/
+pub trait LendingStream: for<'any> LendingIteratorItem<'any> {
+    /// Attempts to pull out the next item of this stream, registering
+    /// the current task for wakeup if the item is not yet available.
+    ///
+    /// The semantics of the returned value are the same as in the case of
+    /// `Stream::poll_next` in the [futures](https://crates.io/crates/futures) crate.
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Item<'_, Self>>>;
+
+    /// Returns a future resolving to the next item of the stream.
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Next { stream: Some(self) }
+    }
+
+    /// Like [`LendingIterator::take`](crate::LendingIterator::take), creates a stream
+    /// that yields the first `n` elements, or fewer if the underlying stream ends sooner.
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take {
+            stream: self,
+            remaining: n,
+        }
+    }
+
+    /// Like [`LendingIterator::map`](crate::LendingIterator::map), takes a closure and
+    /// creates a stream which calls that closure on each element.
+    fn map<NewItemType, F>(self, map: F) -> Map<Self, F, NewItemType>
+    where
+        Self: Sized,
+        F: FnMut(Item<'_, Self>) -> NewItemType,
+    {
+        Map { stream: self, map }
+    }
+
+    /// Like [`Iterator::filter`], creates a stream which uses a closure to
+    /// determine if an element should be yielded.
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&'_ Item<'_, Self>) -> bool,
+    {
+        Filter {
+            stream: self,
+            predicate,
+        }
+    }
+}
+
+/// This future is returned by [`LendingStream::next`].
+#[derive(Debug)]
+pub struct Next<'a, S: ?Sized> {
+    stream: Option<&'a mut S>,
+}
+
+impl<'a, S: LendingStream + Unpin + ?Sized> Future for Next<'a, S> {
+    type Output = Option<Item<'a, S>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let stream: *mut S = *this.stream.as_mut().expect("Next polled after completion");
+        // SAFETY: `stream` comes from the `&'a mut S` we hold, so it can be
+        // reborrowed for 'a as long as that reference is not used again while
+        // the reborrow is live. A pending poll carries no item, so the reborrow
+        // ends here. A ready poll may carry an item borrowing the stream for 'a,
+        // so we discard our reference, and any further poll panics instead of
+        // creating a second borrow.
+        let poll = Pin::new(unsafe { &mut *stream }).poll_next(cx);
+        if poll.is_ready() {
+            this.stream = None;
+        }
+        poll
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIteratorItem, LendingStream};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// This struct is returned by [`LendingStream::map`].
+#[derive(Clone, Debug)]
+pub struct Map<S: LendingStream, F, NewItemType>
+where
+    F: FnMut(<S as LendingIteratorItem>::Type) -> NewItemType,
+{
+    pub(crate) stream: S,
+    pub(crate) map: F,
+}
+
+impl<S: LendingStream + Unpin, F, NewItemType> Unpin for Map<S, F, NewItemType> where
+    F: FnMut(<S as LendingIteratorItem>::Type) -> NewItemType
+{
+}
+
+impl<'any, S: LendingStream, NewItemType, F> LendingIteratorItem<'any> for Map<S, F, NewItemType>
+where
+    F: FnMut(<S as LendingIteratorItem>::Type) -> NewItemType,
+{
+    type Type = NewItemType;
+}
+
+impl<S, NewItemType, F> LendingStream for Map<S, F, NewItemType>
+where
+    S: LendingStream + Unpin,
+    F: FnMut(<S as LendingIteratorItem>::Type) -> NewItemType,
+{
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Item<'_, Self>>> {
+        let this = self.get_mut();
+        Pin::new(&mut this.stream)
+            .poll_next(cx)
+            .map(|item| item.map(&mut this.map))
+    }
+}
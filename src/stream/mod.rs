@@ -0,0 +1,20 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+mod lending_stream;
+pub use lending_stream::LendingStream;
+
+mod from;
+pub use from::stream_from_lend_iter;
+
+mod filter;
+pub use filter::Filter;
+
+mod map;
+pub use map::Map;
+
+mod take;
+pub use take::Take;
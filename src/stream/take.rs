@@ -0,0 +1,34 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIteratorItem, LendingStream};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// This struct is returned by [`LendingStream::take`].
+#[derive(Clone, Debug)]
+pub struct Take<S> {
+    pub(crate) stream: S,
+    pub(crate) remaining: usize,
+}
+
+impl<'any, S: LendingStream> LendingIteratorItem<'any> for Take<S> {
+    type Type = <S as LendingIteratorItem<'any>>::Type;
+}
+
+impl<S: LendingStream + Unpin> LendingStream for Take<S> {
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Item<'_, Self>>> {
+        let this = self.get_mut();
+        if this.remaining == 0 {
+            return Poll::Ready(None);
+        }
+        let poll = Pin::new(&mut this.stream).poll_next(cx);
+        if poll.is_ready() {
+            this.remaining -= 1;
+        }
+        poll
+    }
+}
@@ -0,0 +1,15 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator};
+
+/// A lending iterator able to return items from both ends.
+///
+/// It plays the same role of [`DoubleEndedIterator`] for [`Iterator`].
+pub trait DoubleEndedLendingIterator: LendingIterator {
+    /// Removes and returns an item from the end of the iterator.
+    fn next_back(&mut self) -> Option<Item<'_, Self>>;
+}
@@ -0,0 +1,112 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{Item, LendingIterator, LendingIteratorItem};
+use core::marker::PhantomData;
+
+/**
+
+An object-safe version of [`LendingIterator`].
+
+[`LendingIterator`] cannot be used as a trait object, as the type of
+its items is specified by a higher-rank trait bound on
+[`LendingIteratorItem`], which cannot be expressed in a `dyn` type.
+This trait replaces the higher-rank trait bound with a type parameter
+`S`, the *shape* of the items, which is in turn a type implementing
+[`LendingIteratorItem`] for all lifetimes, such as [`RefItem`],
+[`RefMutItem`] or [`OwnedItem`]. The trait is implemented by every
+[`LendingIterator`] whose items have the same type as those of the shape.
+
+Usually this trait is not used directly, but through
+[`BoxedLendingIterator`](crate::BoxedLendingIterator), which can be
+obtained with [`LendingIterator::boxed`].
+
+*/
+pub trait DynLendingIterator<S: ?Sized + for<'any> LendingIteratorItem<'any>> {
+    /// Like [`LendingIterator::next`], but object safe.
+    fn dyn_next(&mut self) -> Option<Item<'_, S>>;
+}
+
+impl<S, I> DynLendingIterator<S> for I
+where
+    S: ?Sized + for<'any> LendingIteratorItem<'any>,
+    I: LendingIterator + for<'any> LendingIteratorItem<'any, Type = Item<'any, S>>,
+{
+    fn dyn_next(&mut self) -> Option<Item<'_, S>> {
+        self.next()
+    }
+}
+
+/// The shape of items of type `&'any T`, for use with [`DynLendingIterator`].
+///
+/// Note that the items of type `&'any [T]` have shape `RefItem<[T]>`.
+pub struct RefItem<T: ?Sized>(PhantomData<T>);
+
+impl<'any, T: ?Sized> LendingIteratorItem<'any> for RefItem<T> {
+    type Type = &'any T;
+}
+
+/// The shape of items of type `&'any mut T`, for use with [`DynLendingIterator`].
+///
+/// Note that the items of type `&'any mut [T]` have shape `RefMutItem<[T]>`.
+pub struct RefMutItem<T: ?Sized>(PhantomData<T>);
+
+impl<'any, T: ?Sized> LendingIteratorItem<'any> for RefMutItem<T> {
+    type Type = &'any mut T;
+}
+
+/// The shape of owned items of type `T`, for use with [`DynLendingIterator`].
+pub struct OwnedItem<T>(PhantomData<T>);
+
+impl<'any, T> LendingIteratorItem<'any> for OwnedItem<T> {
+    type Type = T;
+}
+
+// The shapes are generic, so they cannot be defined using lender's
+// covariant_lend! macro, which defines a new non-generic type. The
+// implementations of CovariantLending below are the same generated by
+// the macro: their body compiles only if the items are covariant.
+#[cfg(feature = "lender")]
+mod lender_shapes {
+    use super::{OwnedItem, RefItem, RefMutItem};
+    use lender::{CovariantLending, CovariantProof, Lending};
+
+    impl<'lend, T: ?Sized> Lending<'lend> for RefItem<T> {
+        type Lend = &'lend T;
+    }
+
+    impl<T: ?Sized> CovariantLending for RefItem<T> {
+        fn __check_covariance<'long: 'short, 'short>(
+            proof: CovariantProof<<Self as Lending<'long>>::Lend>,
+        ) -> CovariantProof<<Self as Lending<'short>>::Lend> {
+            proof
+        }
+    }
+
+    impl<'lend, T: ?Sized> Lending<'lend> for RefMutItem<T> {
+        type Lend = &'lend mut T;
+    }
+
+    impl<T: ?Sized> CovariantLending for RefMutItem<T> {
+        fn __check_covariance<'long: 'short, 'short>(
+            proof: CovariantProof<<Self as Lending<'long>>::Lend>,
+        ) -> CovariantProof<<Self as Lending<'short>>::Lend> {
+            proof
+        }
+    }
+
+    impl<T> Lending<'_> for OwnedItem<T> {
+        type Lend = T;
+    }
+
+    impl<T> CovariantLending for OwnedItem<T> {
+        fn __check_covariance<'long: 'short, 'short>(
+            proof: CovariantProof<<Self as Lending<'long>>::Lend>,
+        ) -> CovariantProof<<Self as Lending<'short>>::Lend> {
+            proof
+        }
+    }
+}
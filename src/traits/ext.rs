@@ -4,7 +4,18 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use crate::{adapters::FromIntoIterator, adapters::FromIterator, sources::WindowsMut};
+#[cfg(feature = "fallible-streaming-iterator")]
+use crate::adapters::FromFallibleStreamingIterator;
+#[cfg(feature = "lender")]
+use crate::adapters::FromLender;
+#[cfg(feature = "streaming-iterator")]
+use crate::adapters::{FromStreamingIterator, FromStreamingIteratorMut};
+#[cfg(feature = "alloc")]
+use crate::sources::{Combinations, Permutations};
+use crate::{
+    adapters::FromGat, adapters::FromIntoIterator, adapters::FromIterator, sources::FocusMut,
+    sources::PairsMut, sources::WindowsMut, GatLendingIterator,
+};
 
 /// Extension trait adding to [`IntoIterator`] the method [`into_into_lend_iter`](IntoIteratorExt::into_into_lend_iter),
 /// which turns an [`IntoIterator`] into a [`IntoLendingIterator`](crate::IntoLendingIterator) without allocation.
@@ -38,7 +49,107 @@ impl<I: Iterator> IteratorExt<I> for I {
     }
 }
 
-/// Extension trait adding to slices the method
+/// Extension trait adding to [`GatLendingIterator`] the method [`into_lend_iter`](GatLendingIteratorExt::into_lend_iter),
+/// which turns a [`GatLendingIterator`] into a [`LendingIterator`](crate::LendingIterator) without allocation.
+///
+/// Thanks to this trait, a [`GatLendingIterator`] can be used directly with [`for_lend!`](crate::for_lend).
+pub trait GatLendingIteratorExt<G: GatLendingIterator> {
+    /// Turn this [`GatLendingIterator`] into a [`LendingIterator`](crate::LendingIterator) without allocation.
+    ///
+    /// Note that his method and
+    /// [`LendingIterator::into_gat`](crate::LendingIterator::into_gat) are mutually inverse.
+    fn into_lend_iter(self) -> FromGat<G>;
+}
+
+impl<G: GatLendingIterator> GatLendingIteratorExt<G> for G {
+    fn into_lend_iter(self) -> FromGat<G> {
+        crate::from_gat(self)
+    }
+}
+
+/// Extension trait adding to [`Lender`](lender::Lender) the method [`into_lend_iter`](LenderExt::into_lend_iter),
+/// which turns a [`Lender`](lender::Lender) into a [`LendingIterator`](crate::LendingIterator) without allocation.
+///
+/// This trait is available only if the `lender` feature is enabled.
+#[cfg(feature = "lender")]
+pub trait LenderExt<L: lender::Lender> {
+    /// Turn this [`Lender`](lender::Lender) into a [`LendingIterator`](crate::LendingIterator) without allocation.
+    ///
+    /// Note that his method and
+    /// [`LendingIterator::into_lender`](crate::LendingIterator::into_lender) are mutually inverse.
+    fn into_lend_iter(self) -> FromLender<L>;
+}
+
+#[cfg(feature = "lender")]
+impl<L: lender::Lender> LenderExt<L> for L {
+    fn into_lend_iter(self) -> FromLender<L> {
+        crate::from_lender(self)
+    }
+}
+
+/// Extension trait adding to [`StreamingIterator`](streaming_iterator::StreamingIterator)
+/// the methods [`into_lend_iter`](StreamingIteratorExt::into_lend_iter) and
+/// [`into_lend_iter_mut`](StreamingIteratorExt::into_lend_iter_mut), which turn a
+/// [`StreamingIterator`](streaming_iterator::StreamingIterator) into a
+/// [`LendingIterator`](crate::LendingIterator) without allocation.
+///
+/// This trait is available only if the `streaming-iterator` feature is enabled.
+#[cfg(feature = "streaming-iterator")]
+pub trait StreamingIteratorExt<S: streaming_iterator::StreamingIterator> {
+    /// Turn this [`StreamingIterator`](streaming_iterator::StreamingIterator) into a
+    /// [`LendingIterator`](crate::LendingIterator) returning shared references without allocation.
+    ///
+    /// Note that this method and
+    /// [`LendingIterator::into_streaming_iter`](crate::LendingIterator::into_streaming_iter) are mutually inverse.
+    fn into_lend_iter(self) -> FromStreamingIterator<S>;
+
+    /// Turn this [`StreamingIteratorMut`](streaming_iterator::StreamingIteratorMut) into a
+    /// [`LendingIterator`](crate::LendingIterator) returning mutable references without allocation.
+    ///
+    /// Note that this method and
+    /// [`LendingIterator::into_streaming_iter_mut`](crate::LendingIterator::into_streaming_iter_mut) are mutually inverse.
+    fn into_lend_iter_mut(self) -> FromStreamingIteratorMut<S>
+    where
+        S: streaming_iterator::StreamingIteratorMut;
+}
+
+#[cfg(feature = "streaming-iterator")]
+impl<S: streaming_iterator::StreamingIterator> StreamingIteratorExt<S> for S {
+    fn into_lend_iter(self) -> FromStreamingIterator<S> {
+        crate::from_streaming_iter(self)
+    }
+
+    fn into_lend_iter_mut(self) -> FromStreamingIteratorMut<S>
+    where
+        S: streaming_iterator::StreamingIteratorMut,
+    {
+        crate::from_streaming_iter_mut(self)
+    }
+}
+
+/// Extension trait adding to [`FallibleStreamingIterator`](fallible_streaming_iterator::FallibleStreamingIterator)
+/// the method [`into_lend_iter`](FallibleStreamingIteratorExt::into_lend_iter), which turns a
+/// [`FallibleStreamingIterator`](fallible_streaming_iterator::FallibleStreamingIterator)
+/// into a [`LendingIterator`](crate::LendingIterator) returning results without allocation.
+///
+/// This trait is available only if the `fallible-streaming-iterator` feature is enabled.
+#[cfg(feature = "fallible-streaming-iterator")]
+pub trait FallibleStreamingIteratorExt<S: fallible_streaming_iterator::FallibleStreamingIterator> {
+    /// Turn this [`FallibleStreamingIterator`](fallible_streaming_iterator::FallibleStreamingIterator)
+    /// into a [`LendingIterator`](crate::LendingIterator) returning results without allocation.
+    fn into_lend_iter(self) -> FromFallibleStreamingIterator<S>;
+}
+
+#[cfg(feature = "fallible-streaming-iterator")]
+impl<S: fallible_streaming_iterator::FallibleStreamingIterator> FallibleStreamingIteratorExt<S>
+    for S
+{
+    fn into_lend_iter(self) -> FromFallibleStreamingIterator<S> {
+        crate::from_fallible_streaming_iter(self)
+    }
+}
+
+/// Extension trait adding to slices methods returning lending iterators, such as
 /// [`windows_mut`](SliceExt::windows_mut), which is like
 /// [`windows`](https://doc.rust-lang.org/std/primitive.slice.html#method.windows), but yields a
 /// lending iterator returning mutable references to arrays.
@@ -46,6 +157,58 @@ pub trait SliceExt<T> {
     /// Like [`windows`](https://doc.rust-lang.org/std/primitive.slice.html#method.windows),
     /// but yields a lending iterator returning mutable references to arrays.
     fn windows_mut<const WINDOW_SIZE: usize>(&mut self) -> WindowsMut<'_, T, WINDOW_SIZE>;
+
+    /// Returns a lending iterator on pairs of mutable references to the elements
+    /// of this slice with indices `i < j`, in lexicographic order of the indices.
+    ///
+    /// Use [`ordered_pairs_mut`](SliceExt::ordered_pairs_mut) to obtain also the
+    /// pairs with `i > j`.
+    fn pairs_mut(&mut self) -> PairsMut<'_, T>;
+
+    /// Returns a lending iterator on pairs of mutable references to the elements
+    /// of this slice with indices `i != j`, in lexicographic order of the indices.
+    fn ordered_pairs_mut(&mut self) -> PairsMut<'_, T>;
+
+    /// Returns a lending iterator that, for each element of this slice, returns
+    /// a [`Focus`](crate::Focus) containing a mutable reference to the element and
+    /// mutable references to the parts of the slice on its left and on its right.
+    ///
+    /// This generalizes [`windows_mut`](SliceExt::windows_mut), as each element can be
+    /// modified looking at all the other elements of the slice.
+    fn focus_mut(&mut self) -> FocusMut<'_, T>;
+
+    /// Returns a lending iterator on all permutations of this slice, which
+    /// are generated in place, without allocating a new slice for each permutation.
+    ///
+    /// The first permutation is the slice itself. All permutations are returned,
+    /// including duplicates if the slice contains equal elements, and at the end of
+    /// the iteration the slice contains the last permutation.
+    ///
+    /// This method is available only if the `alloc` feature is enabled.
+    #[cfg(feature = "alloc")]
+    fn permutations_lend(&mut self) -> Permutations<&mut [T]>;
+
+    /// Returns a lending iterator on all combinations of `k` elements of this slice,
+    /// in lexicographic order of the indices of the elements.
+    ///
+    /// The elements of each combination are copied into a buffer that is reused
+    /// across combinations, and their indices are available using the
+    /// `indices` method of the returned iterator.
+    ///
+    /// This method is available only if the `alloc` feature is enabled.
+    #[cfg(feature = "alloc")]
+    fn combinations_lend(&self, k: usize) -> Combinations<'_, T>
+    where
+        T: Clone;
+
+    /// Like [`combinations_lend`](SliceExt::combinations_lend), but each element
+    /// can appear multiple times in a combination.
+    ///
+    /// This method is available only if the `alloc` feature is enabled.
+    #[cfg(feature = "alloc")]
+    fn combinations_with_replacement_lend(&self, k: usize) -> Combinations<'_, T>
+    where
+        T: Clone;
 }
 
 impl<T> SliceExt<T> for [T] {
@@ -55,4 +218,41 @@ impl<T> SliceExt<T> for [T] {
             curr_pos: 0,
         }
     }
+
+    fn focus_mut(&mut self) -> FocusMut<'_, T> {
+        FocusMut {
+            back: self.len(),
+            slice: self,
+            front: 0,
+        }
+    }
+
+    fn pairs_mut(&mut self) -> PairsMut<'_, T> {
+        PairsMut::new(self, false)
+    }
+
+    fn ordered_pairs_mut(&mut self) -> PairsMut<'_, T> {
+        PairsMut::new(self, true)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn permutations_lend(&mut self) -> Permutations<&mut [T]> {
+        Permutations::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn combinations_lend(&self, k: usize) -> Combinations<'_, T>
+    where
+        T: Clone,
+    {
+        Combinations::new(self, k, false)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn combinations_with_replacement_lend(&self, k: usize) -> Combinations<'_, T>
+    where
+        T: Clone,
+    {
+        Combinations::new(self, k, true)
+    }
 }
@@ -0,0 +1,84 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{IntoLendingIterator, LendingIterator};
+#[cfg(feature = "alloc")]
+use crate::{IntoOwnedItem, Item, LendingIteratorItem};
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/**
+
+A trait for types that can be built from a [`LendingIterator`].
+
+It plays the same role of [`core::iter::FromIterator`] for [`Iterator`]. Since
+the items returned by a lending iterator are invalidated by the following
+call to [`LendingIterator::next`], implementations must take ownership of each
+item, usually via [`IntoOwnedItem`].
+
+Differently from [`core::iter::FromIterator`], the trait is parameterized by the
+lending iterator rather than by its item type, as the latter depends
+on a lifetime. Usually the trait is not used directly, but
+through [`LendingIterator::collect`].
+
+The implementations for [`Vec`], [`String`] and `BTreeMap` require the `alloc`
+feature, and the implementation for `HashMap` requires the `std` feature.
+
+*/
+pub trait FromLendingIterator<L: LendingIterator>: Sized {
+    /// Creates a value from a lending iterator.
+    fn from_lend_iter<I: IntoLendingIterator<IntoLendIter = L>>(iter: I) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L: LendingIterator> FromLendingIterator<L> for Vec<T>
+where
+    for<'any> Item<'any, L>: IntoOwnedItem<Owned = T>,
+{
+    fn from_lend_iter<I: IntoLendingIterator<IntoLendIter = L>>(iter: I) -> Self {
+        iter.into_lend_iter().to_owned_item().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<L: LendingIterator> FromLendingIterator<L> for String
+where
+    L: for<'any> LendingIteratorItem<'any, Type = &'any str>,
+{
+    fn from_lend_iter<I: IntoLendingIterator<IntoLendIter = L>>(iter: I) -> Self {
+        iter.into_lend_iter().fold(String::new(), |mut s, item| {
+            s.push_str(item);
+            s
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S, L: LendingIterator> FromLendingIterator<L> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+    for<'any> Item<'any, L>: IntoOwnedItem<Owned = (K, V)>,
+{
+    fn from_lend_iter<I: IntoLendingIterator<IntoLendIter = L>>(iter: I) -> Self {
+        iter.into_lend_iter().to_owned_item().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V, L: LendingIterator> FromLendingIterator<L> for BTreeMap<K, V>
+where
+    K: Ord,
+    for<'any> Item<'any, L>: IntoOwnedItem<Owned = (K, V)>,
+{
+    fn from_lend_iter<I: IntoLendingIterator<IntoLendIter = L>>(iter: I) -> Self {
+        iter.into_lend_iter().to_owned_item().collect()
+    }
+}
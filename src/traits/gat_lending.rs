@@ -0,0 +1,34 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/**
+
+The “obvious” lending iterator trait based on generic associated types (GATs).
+
+This trait is not used by the rest of the crate: it is provided as a bridge
+towards crates using this simpler design. A [`GatLendingIterator`] can be turned
+into a [`LendingIterator`](crate::LendingIterator) without allocating using
+[`GatLendingIteratorExt::into_lend_iter`](crate::GatLendingIteratorExt::into_lend_iter),
+and thus used with the adapters of this crate and with [`for_lend!`](crate::for_lend).
+Conversely, the method [`LendingIterator::into_gat`](crate::LendingIterator::into_gat)
+turns a [`LendingIterator`](crate::LendingIterator) into a [`GatLendingIterator`].
+
+Note that the restrictions of this design carry over to the converted iterator:
+since the type `Self::Item<'a>` is well formed only if `Self: 'a`, bounds quantifying
+over all lifetimes of the items, such as the one of
+[`LendingIterator::map`](crate::LendingIterator::map), can be satisfied only if
+the [`GatLendingIterator`] is `'static`.
+
+*/
+pub trait GatLendingIterator {
+    /// The type of the items returned by the iterator.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Advances the iterator and returns the next item.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
@@ -4,10 +4,15 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
+#[cfg(feature = "alloc")]
+use crate::adapters::ToIntoOwnedItemIterator;
 use crate::{
-    adapters::{IntoIntoIter, ToIntoOwnedItemIterator},
+    adapters::IntoIntoIter,
+    sources::{SliceIter, SliceIterMut},
     LendingIterator,
 };
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 /**
 
@@ -21,6 +26,10 @@ Ideally, types that have a method that returns a [`LendingIterator`]
 should implement this trait on a reference and delegate
 [`IntoLendingIterator::into_lend_iter`] to such methods.
 
+The trait is also implemented, like [`IntoIterator`], for (mutable) references
+to slices, arrays and vectors, so that, for example, a lending iterator
+returning such references can be [flattened](LendingIterator::flatten).
+
 */
 pub trait IntoLendingIterator {
     /// Which kind of lending iterator are we turning this into?
@@ -49,8 +58,9 @@ pub trait IntoLendingIterator {
     /// by applying [`LendingIterator::to_owned_item`] to the result
     /// of [`IntoLendingIterator::into_lend_iter`].
     ///
-    /// This method is only available if the type referred by
-    /// the item type implements [`ToOwned`].
+    /// This method is only available if the item type of the associated
+    /// iterator implements [`IntoOwnedItem`](crate::IntoOwnedItem).
+    #[cfg(feature = "alloc")]
     fn to_into_owned_item(self) -> ToIntoOwnedItemIterator<Self>
     where
         Self: Sized,
@@ -66,3 +76,53 @@ impl<I: LendingIterator> IntoLendingIterator for I {
         self
     }
 }
+
+impl<'a, T> IntoLendingIterator for &'a [T] {
+    type IntoLendIter = SliceIter<'a, T>;
+
+    fn into_lend_iter(self) -> Self::IntoLendIter {
+        SliceIter { slice: self }
+    }
+}
+
+impl<'a, T> IntoLendingIterator for &'a mut [T] {
+    type IntoLendIter = SliceIterMut<'a, T>;
+
+    fn into_lend_iter(self) -> Self::IntoLendIter {
+        SliceIterMut { slice: self }
+    }
+}
+
+impl<'a, T, const N: usize> IntoLendingIterator for &'a [T; N] {
+    type IntoLendIter = SliceIter<'a, T>;
+
+    fn into_lend_iter(self) -> Self::IntoLendIter {
+        SliceIter { slice: self }
+    }
+}
+
+impl<'a, T, const N: usize> IntoLendingIterator for &'a mut [T; N] {
+    type IntoLendIter = SliceIterMut<'a, T>;
+
+    fn into_lend_iter(self) -> Self::IntoLendIter {
+        SliceIterMut { slice: self }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IntoLendingIterator for &'a Vec<T> {
+    type IntoLendIter = SliceIter<'a, T>;
+
+    fn into_lend_iter(self) -> Self::IntoLendIter {
+        SliceIter { slice: self }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IntoLendingIterator for &'a mut Vec<T> {
+    type IntoLendIter = SliceIterMut<'a, T>;
+
+    fn into_lend_iter(self) -> Self::IntoLendIter {
+        SliceIterMut { slice: self }
+    }
+}
@@ -0,0 +1,108 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use alloc::borrow::ToOwned;
+
+/**
+
+A trait for items of a [`LendingIterator`](crate::LendingIterator) that
+can be detached from the iterator, yielding an owned value.
+
+This is the trait used by [`LendingIterator::to_owned_item`](crate::LendingIterator::to_owned_item).
+It is implemented for shared and mutable references to types implementing [`ToOwned`],
+for [`Option`] and tuples (up to arity six) of types implementing this trait, and for
+primitive types, which are already owned. Thus, for example, the items of
+[`SliceExt::windows_mut`](crate::SliceExt::windows_mut), which are of type
+`&'any mut [T; N]`, and those of [`LendingIterator::enumerate`](crate::LendingIterator::enumerate),
+which are of type `(usize, &'any T)`, can be detached.
+
+*/
+pub trait IntoOwnedItem {
+    /// The owned type we are turning this item into.
+    type Owned;
+
+    /// Turns this item into an owned value.
+    fn into_owned_item(self) -> Self::Owned;
+}
+
+impl<T: ?Sized + ToOwned> IntoOwnedItem for &T {
+    type Owned = T::Owned;
+
+    fn into_owned_item(self) -> Self::Owned {
+        self.to_owned()
+    }
+}
+
+impl<T: ?Sized + ToOwned> IntoOwnedItem for &mut T {
+    type Owned = T::Owned;
+
+    fn into_owned_item(self) -> Self::Owned {
+        (*self).to_owned()
+    }
+}
+
+impl<A: IntoOwnedItem> IntoOwnedItem for Option<A> {
+    type Owned = Option<A::Owned>;
+
+    fn into_owned_item(self) -> Self::Owned {
+        self.map(A::into_owned_item)
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: IntoOwnedItem),+> IntoOwnedItem for ($($name,)+) {
+            type Owned = ($($name::Owned,)+);
+
+            #[allow(non_snake_case)]
+            fn into_owned_item(self) -> Self::Owned {
+                let ($($name,)+) = self;
+                ($($name.into_owned_item(),)+)
+            }
+        }
+    };
+}
+
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+
+macro_rules! impl_owned {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoOwnedItem for $ty {
+                type Owned = $ty;
+
+                fn into_owned_item(self) -> Self::Owned {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_owned!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64
+);
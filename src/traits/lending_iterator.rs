@@ -7,6 +7,7 @@
  */
 
 use crate::adapters::*;
+use crate::{DoubleEndedLendingIterator, ExactSizeLendingIterator, FromLendingIterator};
 
 /// A trait specifying the type of the items of a [LendingIterator].
 ///
@@ -48,7 +49,6 @@ write types such as `PermutedGraph` or `ArcListGraph` in
 
 
 */
-
 pub trait LendingIterator: for<'any> LendingIteratorItem<'any> {
     fn next(&mut self) -> Option<Item<'_, Self>>;
 
@@ -88,6 +88,19 @@ pub trait LendingIterator: for<'any> LendingIteratorItem<'any> {
         Inspect { iter: self, f }
     }
 
+    /// Like [`inspect`](LendingIterator::inspect), but the closure receives a mutable
+    /// reference to each element, which is then passed on.
+    ///
+    /// In this way, the closure can modify the element, or, if the element is
+    /// itself a mutable reference, the value it refers to.
+    fn inspect_mut<F>(self, f: F) -> InspectMut<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&'_ mut Item<'_, Self>),
+    {
+        InspectMut { iter: self, f }
+    }
+
     /// Like [`Iterator::map`], takes a closure and creates an iterator which calls
     /// that closure on each element.
     fn map<NewItemType, F>(self, map: F) -> Map<Self, F, NewItemType>
@@ -98,6 +111,201 @@ pub trait LendingIterator: for<'any> LendingIteratorItem<'any> {
         Map { iter: self, map }
     }
 
+    /// Like [`Iterator::scan`], creates an iterator that holds an internal state
+    /// and calls a closure on the state and each element.
+    ///
+    /// The closure receives a mutable reference to the state and the lent item,
+    /// and returns the next element of the resulting iterator, or [`None`] to
+    /// stop the iteration. As with [`map`](LendingIterator::map), the elements returned
+    /// by the closure cannot borrow from the items.
+    fn scan<St, B, F>(self, initial_state: St, f: F) -> Scan<Self, St, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Item<'_, Self>) -> Option<B>,
+    {
+        Scan {
+            iter: self,
+            state: initial_state,
+            f,
+        }
+    }
+
+    /// Like [`Iterator::map_while`], creates an iterator that both yields elements based
+    /// on a predicate and maps.
+    ///
+    /// The iteration stops at the first [`None`] returned by the predicate.
+    fn map_while<B, P>(self, predicate: P) -> MapWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Item<'_, Self>) -> Option<B>,
+    {
+        MapWhile {
+            iter: self,
+            predicate,
+        }
+    }
+
+    /// Like [`Iterator::flat_map`], creates an iterator that works like
+    /// [`map`](LendingIterator::map), but flattens nested structure.
+    ///
+    /// The closure must return an [`IntoLendingIterator`](crate::IntoLendingIterator),
+    /// whose items are returned in turn; an [`IntoIterator`] can be returned by turning it
+    /// into an [`IntoLendingIterator`](crate::IntoLendingIterator) with
+    /// [`into_into_lend_iter`](crate::IntoIteratorExt::into_into_lend_iter). As with
+    /// [`map`](LendingIterator::map), the value returned by the closure cannot
+    /// borrow from the items: use [`flatten`](LendingIterator::flatten) in that case.
+    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, F, U>
+    where
+        Self: Sized,
+        F: FnMut(Item<'_, Self>) -> U,
+        U: crate::IntoLendingIterator,
+    {
+        FlatMap {
+            iter: self,
+            f,
+            inner: None,
+        }
+    }
+
+    /// Like [`Iterator::flatten`], creates an iterator that flattens nested
+    /// structure, when the items of this iterator implement
+    /// [`IntoLendingIterator`](crate::IntoLendingIterator).
+    ///
+    /// The items of the inner iterators might borrow from the items of this
+    /// iterator, which in turn might borrow from this iterator: for this reason,
+    /// this iterator is boxed, and the items are returned with shape `S`, similarly
+    /// to [`boxed`](LendingIterator::boxed). The shape constructor must be usually
+    /// specified explicitly, as in `iter.flatten::<RefItem<_>>()`.
+    ///
+    /// Items that are (mutable) references to slices, arrays or vectors implement
+    /// [`IntoLendingIterator`](crate::IntoLendingIterator), so they can be flattened
+    /// as well. Items implementing just [`IntoIterator`] cannot be flattened, as
+    /// [`IntoLendingIterator`](crate::IntoLendingIterator) cannot be implemented
+    /// for all of them without conflicting with the implementation for lending
+    /// iterators: if they do not borrow from this iterator, use
+    /// [`flat_map`](LendingIterator::flat_map) with
+    /// [`into_into_lend_iter`](crate::IntoIteratorExt::into_into_lend_iter) instead.
+    #[cfg(feature = "alloc")]
+    fn flatten<'this, S>(self) -> Flatten<'this, Self, S>
+    where
+        Self: Sized + 'this,
+        for<'any> Item<'any, Self>: crate::IntoLendingIterator,
+        for<'any> <Item<'any, Self> as crate::IntoLendingIterator>::IntoLendIter:
+            crate::DynLendingIterator<S>,
+        S: ?Sized + for<'any> LendingIteratorItem<'any>,
+    {
+        Flatten::new(self)
+    }
+
+    /// Creates an iterator which places a copy of `separator` between adjacent
+    /// items of this iterator, like the currently unstable `Iterator::intersperse`.
+    ///
+    /// This method is only available if the items returned
+    /// by the iterator are owned (i.e., if the iterator is
+    /// not really lending).
+    fn intersperse<T: Clone>(self, separator: T) -> Intersperse<Self, T>
+    where
+        Self: Sized + for<'any> LendingIteratorItem<'any, Type = T>,
+    {
+        Intersperse {
+            iter: self,
+            separator,
+            next_item: None,
+            started: false,
+        }
+    }
+
+    /// Creates an iterator which places an item generated by `separator`
+    /// between adjacent items of this iterator, like the currently unstable
+    /// `Iterator::intersperse_with`.
+    ///
+    /// This method is only available if the items returned
+    /// by the iterator are owned (i.e., if the iterator is
+    /// not really lending).
+    fn intersperse_with<T, G>(self, separator: G) -> IntersperseWith<Self, G, T>
+    where
+        Self: Sized + for<'any> LendingIteratorItem<'any, Type = T>,
+        G: FnMut() -> T,
+    {
+        IntersperseWith {
+            iter: self,
+            separator,
+            next_item: None,
+            started: false,
+        }
+    }
+
+    /// Creates an iterator that skips the items equal to the previous returned
+    /// item, like [`Vec::dedup`](alloc::vec::Vec::dedup).
+    ///
+    /// Since the returned items are invalidated by the following call to
+    /// [`next`](LendingIterator::next), the iterator retains an owned copy, obtained
+    /// via [`ToOwned`](alloc::borrow::ToOwned), of the last returned item.
+    /// This method is thus only available if the returned items dereference to
+    /// a type implementing [`ToOwned`](alloc::borrow::ToOwned), as it happens for
+    /// `&'any T` and `&'any mut T`.
+    #[cfg(feature = "alloc")]
+    fn dedup<T>(self) -> Dedup<Self, T>
+    where
+        Self: Sized,
+        for<'any> Item<'any, Self>: core::ops::Deref<Target = T>,
+        T: ?Sized + alloc::borrow::ToOwned + PartialEq,
+    {
+        Dedup {
+            iter: self,
+            prev: None,
+        }
+    }
+
+    /// Like [`dedup`](LendingIterator::dedup), but uses the given closure to decide
+    /// whether an item is equal to the owned copy of the last returned item.
+    ///
+    /// The closure is passed the owned copy and the current item, and
+    /// the current item is skipped if the closure returns `true`.
+    #[cfg(feature = "alloc")]
+    fn dedup_by<T, F>(self, same_bucket: F) -> DedupBy<Self, T, F>
+    where
+        Self: Sized,
+        for<'any> Item<'any, Self>: core::ops::Deref<Target = T>,
+        T: ?Sized + alloc::borrow::ToOwned,
+        F: FnMut(&T::Owned, &Item<'_, Self>) -> bool,
+    {
+        DedupBy {
+            iter: self,
+            prev: None,
+            same_bucket,
+        }
+    }
+
+    /// Creates an iterator that skips the items whose key, computed by the
+    /// given closure, is equal to that of the previous returned item, like
+    /// [`Vec::dedup_by_key`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup_by_key).
+    ///
+    /// The key must be owned, as the iterator retains the key of the last returned item.
+    fn dedup_by_key<K, F>(self, key: F) -> DedupByKey<Self, K, F>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Item<'_, Self>) -> K,
+    {
+        DedupByKey {
+            iter: self,
+            prev: None,
+            key,
+        }
+    }
+
+    /// Like [`Iterator::by_ref`], borrows an iterator, rather than consuming it.
+    ///
+    /// This is useful to allow applying adapters while still retaining
+    /// ownership of the original iterator.
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     /// Like [`Iterator::fold`], folds every element into an accumulator by applying
     /// an operation, returning the final result.
     fn fold<B, F>(mut self, init: B, mut f: F) -> B
@@ -120,6 +328,335 @@ pub trait LendingIterator: for<'any> LendingIteratorItem<'any> {
         self.fold((), |(), item| f(item))
     }
 
+    /// Like [`Iterator::collect`], transforms an iterator into a collection.
+    ///
+    /// Since items are lent, the collection must take ownership of them:
+    /// see [`FromLendingIterator`] for the available implementations.
+    fn collect<B>(self) -> B
+    where
+        Self: Sized,
+        B: FromLendingIterator<Self>,
+    {
+        B::from_lend_iter(self)
+    }
+
+    /// Like [`Iterator::sum`], sums the items of the iterator.
+    ///
+    /// Since items are lent, [`Sum`](core::iter::Sum) is applied to one item at a
+    /// time, and the partial results are summed in turn: thus, `S` must be summable
+    /// both from the items and from itself. This is the case, for example, for
+    /// iterators lending references to numbers, as `i32` implements both `Sum<i32>`
+    /// and `Sum<&i32>`.
+    ///
+    /// The sum is computed using [`fold`](LendingIterator::fold), so adapters
+    /// overriding the latter speed up this method, too.
+    fn sum<S>(self) -> S
+    where
+        Self: Sized,
+        S: core::iter::Sum<S> + for<'any> core::iter::Sum<Item<'any, Self>>,
+    {
+        self.fold(core::iter::empty::<S>().sum(), |sum, item| {
+            [sum, core::iter::once(item).sum()].into_iter().sum()
+        })
+    }
+
+    /// Like [`Iterator::product`], multiplies the items of the iterator.
+    ///
+    /// See [`sum`](LendingIterator::sum) for details.
+    fn product<P>(self) -> P
+    where
+        Self: Sized,
+        P: core::iter::Product<P> + for<'any> core::iter::Product<Item<'any, Self>>,
+    {
+        self.fold(core::iter::empty::<P>().product(), |product, item| {
+            [product, core::iter::once(item).product()]
+                .into_iter()
+                .product()
+        })
+    }
+
+    /// Sums the values computed by the given closure on the items.
+    ///
+    /// For example, the sum of the elements of all windows returned by
+    /// [`SliceExt::windows_mut`](crate::SliceExt::windows_mut) can be computed with
+    /// `iter.sum_by(|w| w.iter().sum::<u64>())`.
+    ///
+    /// See [`sum`](LendingIterator::sum) for details.
+    fn sum_by<T, F>(self, mut f: F) -> T
+    where
+        Self: Sized,
+        T: core::iter::Sum<T>,
+        F: FnMut(&Item<'_, Self>) -> T,
+    {
+        self.fold(core::iter::empty::<T>().sum(), |sum, item| {
+            [sum, f(&item)].into_iter().sum()
+        })
+    }
+
+    /// Like [`Iterator::eq_by`], determines if the items of this iterator are equal to
+    /// those of another with respect to the given equality function.
+    ///
+    /// The other iterator can be any [`IntoLendingIterator`](crate::IntoLendingIterator),
+    /// including an [`IntoIterator`] turned into an [`IntoLendingIterator`](crate::IntoLendingIterator) with
+    /// [`into_into_lend_iter`](crate::IntoIteratorExt::into_into_lend_iter). The equality
+    /// function is passed references to the items, as they are lent.
+    fn eq_by<J, F>(mut self, other: J, mut eq: F) -> bool
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        F: FnMut(&Item<'_, Self>, &Item<'_, J::IntoLendIter>) -> bool,
+    {
+        let mut other = other.into_lend_iter();
+        loop {
+            match (self.next(), other.next()) {
+                (None, None) => return true,
+                (Some(a), Some(b)) if eq(&a, &b) => {}
+                _ => return false,
+            }
+        }
+    }
+
+    /// Like [`Iterator::eq`], determines if the items of this iterator are equal to
+    /// those of another.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details. Note that the items of
+    /// the other iterator appear on the left-hand side of the [`PartialEq`] bound, as
+    /// otherwise the current compiler cannot infer the type of the other iterator.
+    /// Compilers older than Rust 1.78 cannot infer it in any case, which sets the
+    /// minimum supported Rust version of this crate.
+    fn eq<J>(self, other: J) -> bool
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        for<'a, 'b> Item<'b, J::IntoLendIter>: PartialEq<Item<'a, Self>>,
+    {
+        self.eq_by(other, |a, b| b == a)
+    }
+
+    /// Like [`Iterator::ne`], determines if the items of this iterator are not equal to
+    /// those of another.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details.
+    fn ne<J>(self, other: J) -> bool
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        for<'a, 'b> Item<'b, J::IntoLendIter>: PartialEq<Item<'a, Self>>,
+    {
+        !self.eq(other)
+    }
+
+    /// Like [`Iterator::cmp_by`], lexicographically compares the items of this iterator
+    /// with those of another with respect to the given comparison function.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details.
+    fn cmp_by<J, F>(mut self, other: J, mut cmp: F) -> core::cmp::Ordering
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        F: FnMut(&Item<'_, Self>, &Item<'_, J::IntoLendIter>) -> core::cmp::Ordering,
+    {
+        use core::cmp::Ordering;
+        let mut other = other.into_lend_iter();
+        loop {
+            match (self.next(), other.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(a), Some(b)) => match cmp(&a, &b) {
+                    Ordering::Equal => {}
+                    non_eq => return non_eq,
+                },
+            }
+        }
+    }
+
+    /// Like [`Iterator::partial_cmp_by`], lexicographically compares the items of this
+    /// iterator with those of another with respect to the given comparison function.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details.
+    fn partial_cmp_by<J, F>(mut self, other: J, mut partial_cmp: F) -> Option<core::cmp::Ordering>
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        F: FnMut(&Item<'_, Self>, &Item<'_, J::IntoLendIter>) -> Option<core::cmp::Ordering>,
+    {
+        use core::cmp::Ordering;
+        let mut other = other.into_lend_iter();
+        loop {
+            match (self.next(), other.next()) {
+                (None, None) => return Some(Ordering::Equal),
+                (None, Some(_)) => return Some(Ordering::Less),
+                (Some(_), None) => return Some(Ordering::Greater),
+                (Some(a), Some(b)) => match partial_cmp(&a, &b) {
+                    Some(Ordering::Equal) => {}
+                    non_eq => return non_eq,
+                },
+            }
+        }
+    }
+
+    /// Like [`Iterator::partial_cmp`], lexicographically compares the items of this
+    /// iterator with those of another.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details.
+    fn partial_cmp<J>(self, other: J) -> Option<core::cmp::Ordering>
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        for<'a, 'b> Item<'b, J::IntoLendIter>: PartialOrd<Item<'a, Self>>,
+    {
+        self.partial_cmp_by(other, |a, b| {
+            b.partial_cmp(a).map(core::cmp::Ordering::reverse)
+        })
+    }
+
+    /// Like [`Iterator::lt`], determines if the items of this iterator are
+    /// lexicographically less than those of another.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details.
+    fn lt<J>(self, other: J) -> bool
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        for<'a, 'b> Item<'b, J::IntoLendIter>: PartialOrd<Item<'a, Self>>,
+    {
+        self.partial_cmp(other) == Some(core::cmp::Ordering::Less)
+    }
+
+    /// Like [`Iterator::le`], determines if the items of this iterator are
+    /// lexicographically less than or equal to those of another.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details.
+    fn le<J>(self, other: J) -> bool
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        for<'a, 'b> Item<'b, J::IntoLendIter>: PartialOrd<Item<'a, Self>>,
+    {
+        self.partial_cmp(other)
+            .is_some_and(core::cmp::Ordering::is_le)
+    }
+
+    /// Like [`Iterator::gt`], determines if the items of this iterator are
+    /// lexicographically greater than those of another.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details.
+    fn gt<J>(self, other: J) -> bool
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        for<'a, 'b> Item<'b, J::IntoLendIter>: PartialOrd<Item<'a, Self>>,
+    {
+        self.partial_cmp(other) == Some(core::cmp::Ordering::Greater)
+    }
+
+    /// Like [`Iterator::ge`], determines if the items of this iterator are
+    /// lexicographically greater than or equal to those of another.
+    ///
+    /// See [`eq_by`](LendingIterator::eq_by) for details.
+    fn ge<J>(self, other: J) -> bool
+    where
+        Self: Sized,
+        J: crate::IntoLendingIterator,
+        for<'a, 'b> Item<'b, J::IntoLendIter>: PartialOrd<Item<'a, Self>>,
+    {
+        self.partial_cmp(other)
+            .is_some_and(core::cmp::Ordering::is_ge)
+    }
+
+    /// Returns the maximum of the keys computed by the given closure on the items, or
+    /// [`None`] if the iterator is empty.
+    ///
+    /// Differently from [`Iterator::max_by_key`], the key is returned in place of the item,
+    /// as the latter is invalidated by the following call to [`next`](LendingIterator::next).
+    /// Use [`max_by_owned`](LendingIterator::max_by_owned) to retain an owned copy of
+    /// the maximum item.
+    fn max_by_key<B: Ord, F>(self, mut f: F) -> Option<B>
+    where
+        Self: Sized,
+        F: FnMut(&Item<'_, Self>) -> B,
+    {
+        self.fold(None, |max, item| {
+            let key = f(&item);
+            match max {
+                Some(max) if max > key => Some(max),
+                _ => Some(key),
+            }
+        })
+    }
+
+    /// Returns the minimum of the keys computed by the given closure on the items, or
+    /// [`None`] if the iterator is empty.
+    ///
+    /// See [`max_by_key`](LendingIterator::max_by_key) for details.
+    fn min_by_key<B: Ord, F>(self, mut f: F) -> Option<B>
+    where
+        Self: Sized,
+        F: FnMut(&Item<'_, Self>) -> B,
+    {
+        self.fold(None, |min, item| {
+            let key = f(&item);
+            match min {
+                Some(min) if min <= key => Some(min),
+                _ => Some(key),
+            }
+        })
+    }
+
+    /// Returns an owned copy of the maximum item with respect to the given comparison
+    /// function, or [`None`] if the iterator is empty.
+    ///
+    /// The comparison function is passed the current item and the owned copy of the
+    /// current maximum, and the item is made owned only if it is not smaller than the
+    /// current maximum, so, as in [`Iterator::max_by`], the last maximum item is returned.
+    ///
+    /// This method is only available if the item type implements
+    /// [`IntoOwnedItem`](crate::IntoOwnedItem).
+    #[cfg(feature = "alloc")]
+    fn max_by_owned<O, F>(self, mut compare: F) -> Option<O>
+    where
+        Self: Sized,
+        for<'any> Item<'any, Self>: crate::IntoOwnedItem<Owned = O>,
+        F: FnMut(&Item<'_, Self>, &O) -> core::cmp::Ordering,
+    {
+        self.fold(None, |max, item| match max {
+            Some(max) if compare(&item, &max).is_lt() => Some(max),
+            _ => Some(crate::IntoOwnedItem::into_owned_item(item)),
+        })
+    }
+
+    /// Returns an owned copy of the last item, or [`None`] if the iterator is empty.
+    ///
+    /// Since it is not possible to know in advance which item is the last one,
+    /// every item is made owned. This method is only available if the item type implements
+    /// [`IntoOwnedItem`](crate::IntoOwnedItem).
+    #[cfg(feature = "alloc")]
+    fn last_owned<O>(self) -> Option<O>
+    where
+        Self: Sized,
+        for<'any> Item<'any, Self>: crate::IntoOwnedItem<Owned = O>,
+    {
+        self.fold(None, |_, item| {
+            Some(crate::IntoOwnedItem::into_owned_item(item))
+        })
+    }
+
+    /// Like [`Iterator::reduce`], reduces the items to a single one by repeatedly
+    /// applying a reducing operation.
+    ///
+    /// This method is only available if the items returned
+    /// by the iterator are owned (i.e., if the iterator is
+    /// not really lending).
+    fn reduce<T, F>(mut self, f: F) -> Option<T>
+    where
+        Self: Sized + for<'any> LendingIteratorItem<'any, Type = T>,
+        F: FnMut(T, T) -> T,
+    {
+        let first = self.next()?;
+        Some(self.fold(first, f))
+    }
+
     /// Turns this [`LendingIterator`] into a regular [`Iterator`],
     /// if possible, without allocating.
     ///
@@ -139,10 +676,12 @@ pub trait LendingIterator: for<'any> LendingIteratorItem<'any> {
 
     /// Turns this [`LendingIterator`] into a regular [`Iterator`]
     /// by getting an owned version of the returned items via
-    /// [`ToOwned`].
+    /// [`IntoOwnedItem`](crate::IntoOwnedItem).
     ///
-    /// This method is only available if the type referred by
-    /// the item type implements [`ToOwned`].
+    /// This method is only available if the item type implements
+    /// [`IntoOwnedItem`](crate::IntoOwnedItem), as it happens, for example,
+    /// for references to types implementing [`ToOwned`].
+    #[cfg(feature = "alloc")]
     fn to_owned_item(self) -> ToOwnedItemIterator<Self>
     where
         Self: Sized,
@@ -150,6 +689,125 @@ pub trait LendingIterator: for<'any> LendingIteratorItem<'any> {
         ToOwnedItemIterator(self)
     }
 
+    /// Like [`Iterator::cloned`], turns this [`LendingIterator`] into a regular
+    /// [`Iterator`] by cloning the values referred by the returned items.
+    ///
+    /// This method is only available if the returned items dereference to
+    /// a type implementing [`Clone`], as it happens for `&'any T` and `&'any mut T`.
+    fn cloned(self) -> Cloned<Self>
+    where
+        Self: Sized,
+    {
+        Cloned(self)
+    }
+
+    /// Like [`Iterator::copied`], turns this [`LendingIterator`] into a regular
+    /// [`Iterator`] by copying the values referred by the returned items.
+    ///
+    /// This method is only available if the returned items dereference to
+    /// a type implementing [`Copy`], as it happens for `&'any T` and `&'any mut T`.
+    fn copied(self) -> Copied<Self>
+    where
+        Self: Sized,
+    {
+        Copied(self)
+    }
+
+    /// Boxes this [`LendingIterator`] into a [`BoxedLendingIterator`](crate::BoxedLendingIterator)
+    /// with items of shape `S`, erasing its type.
+    ///
+    /// This method is only available if this iterator implements
+    /// [`DynLendingIterator<S>`](crate::DynLendingIterator). The shape
+    /// constructor must be usually specified explicitly, as in
+    /// `iter.boxed::<RefItem<_>>()`.
+    #[cfg(feature = "alloc")]
+    fn boxed<'a, S>(self) -> BoxedLendingIterator<'a, S>
+    where
+        Self: Sized + crate::DynLendingIterator<S> + 'a,
+        S: ?Sized + for<'any> LendingIteratorItem<'any>,
+    {
+        BoxedLendingIterator::new(self)
+    }
+
+    /// Turns this [`LendingIterator`] into a [`GatLendingIterator`](crate::GatLendingIterator)
+    /// without allocating.
+    ///
+    /// Note that this method and
+    /// [`GatLendingIteratorExt::into_lend_iter`](crate::GatLendingIteratorExt::into_lend_iter)
+    /// are mutually inverse.
+    fn into_gat(self) -> IntoGat<Self>
+    where
+        Self: Sized,
+    {
+        IntoGat(self)
+    }
+
+    /// Turns this [`LendingIterator`] into a [`Lender`](lender::Lender) whose items have
+    /// shape `S`, without allocating.
+    ///
+    /// Since [`Lender`](lender::Lender) requires its items to be covariant in their
+    /// lifetime, the shape must implement [`CovariantLending`](lender::CovariantLending):
+    /// it can be one of [`RefItem`](crate::RefItem), [`RefMutItem`](crate::RefMutItem)
+    /// and [`OwnedItem`](crate::OwnedItem), or a type built with [`lender::lend!`].
+    /// The shape constructor must be usually specified explicitly, as in
+    /// `iter.into_lender::<RefItem<_>>()`.
+    ///
+    /// The resulting [`Lender`](lender::Lender) implements [`ExactSizeLender`](lender::ExactSizeLender)
+    /// and [`DoubleEndedLender`](lender::DoubleEndedLender) if this iterator
+    /// implements [`ExactSizeLendingIterator`] and [`DoubleEndedLendingIterator`], respectively.
+    ///
+    /// Note that this method and [`LenderExt::into_lend_iter`](crate::LenderExt::into_lend_iter)
+    /// are mutually inverse.
+    ///
+    /// This method is available only if the `lender` feature is enabled.
+    #[cfg(feature = "lender")]
+    fn into_lender<S>(self) -> IntoLender<Self, S>
+    where
+        Self: Sized + for<'any> LendingIteratorItem<'any, Type = lender::Lend<'any, S>>,
+        S: ?Sized + lender::CovariantLending,
+    {
+        // Required by the documentation of CovariantLending; as the check is
+        // not part of the stable API of lender, its version is pinned.
+        lender::__check_lending_covariance::<S>();
+        IntoLender {
+            iter: self,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Turns this [`LendingIterator`] returning items of type `&'any T` into a
+    /// [`StreamingIterator`](streaming_iterator::StreamingIterator).
+    ///
+    /// Since a [`StreamingIterator`](streaming_iterator::StreamingIterator) must be
+    /// able to return the current item from a shared reference, this iterator is boxed,
+    /// so that the item it lends remains valid when the adapter is moved.
+    ///
+    /// The result implements [`DoubleEndedStreamingIterator`](streaming_iterator::DoubleEndedStreamingIterator)
+    /// if this iterator implements [`DoubleEndedLendingIterator`].
+    ///
+    /// This method is available only if the `streaming-iterator` and `alloc` features are enabled.
+    #[cfg(all(feature = "streaming-iterator", feature = "alloc"))]
+    fn into_streaming_iter<T: ?Sized>(self) -> IntoStreamingIterator<Self, T>
+    where
+        Self: Sized + for<'any> LendingIteratorItem<'any, Type = &'any T>,
+    {
+        IntoStreamingIterator::new(self)
+    }
+
+    /// Turns this [`LendingIterator`] returning items of type `&'any mut T` into a
+    /// [`StreamingIteratorMut`](streaming_iterator::StreamingIteratorMut).
+    ///
+    /// See [`into_streaming_iter`](LendingIterator::into_streaming_iter) for details.
+    ///
+    /// This method is available only if the `streaming-iterator` and `alloc` features are enabled.
+    #[cfg(all(feature = "streaming-iterator", feature = "alloc"))]
+    fn into_streaming_iter_mut<T: ?Sized>(self) -> IntoStreamingIteratorMut<Self, T>
+    where
+        Self: Sized + for<'any> LendingIteratorItem<'any, Type = &'any mut T>,
+    {
+        IntoStreamingIteratorMut::new(self)
+    }
+
     /// Like [`Iterator::enumerate`], creates an iterator which gives the current
     /// iteration count as well as the next value.
     fn enumerate(self) -> Enumerate<Self>
@@ -180,4 +838,47 @@ impl<I: LendingIterator> LendingIterator for Take<I> {
             None
         }
     }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Item<'_, Self>) -> B,
+    {
+        let mut accum = init;
+        for _ in 0..self.remaining {
+            match self.iter.next() {
+                Some(x) => accum = f(accum, x),
+                None => break,
+            }
+        }
+        accum
+    }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for Take<I> {
+    fn len(&self) -> usize {
+        self.iter.len().min(self.remaining)
+    }
+}
+
+impl<'any, I: LendingIterator + ?Sized> LendingIteratorItem<'any> for &mut I {
+    type Type = <I as LendingIteratorItem<'any>>::Type;
+}
+
+impl<I: LendingIterator + ?Sized> LendingIterator for &mut I {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        (**self).next()
+    }
+}
+
+impl<I: ExactSizeLendingIterator + ?Sized> ExactSizeLendingIterator for &mut I {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+impl<I: DoubleEndedLendingIterator + ?Sized> DoubleEndedLendingIterator for &mut I {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        (**self).next_back()
+    }
 }
@@ -4,16 +4,40 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
+mod double_ended;
+pub use double_ended::DoubleEndedLendingIterator;
+
+mod dyn_lending;
+pub use dyn_lending::{DynLendingIterator, OwnedItem, RefItem, RefMutItem};
+
 mod exact_size;
 pub use exact_size::ExactSizeLendingIterator;
 
+mod from_lending;
+pub use from_lending::FromLendingIterator;
+
+mod gat_lending;
+pub use gat_lending::GatLendingIterator;
+
 mod into_lending;
 pub use into_lending::IntoLendingIterator;
 
+#[cfg(feature = "alloc")]
+mod into_owned_item;
+#[cfg(feature = "alloc")]
+pub use into_owned_item::IntoOwnedItem;
+
 mod lending_iterator;
 pub use lending_iterator::{Item, LendingIterator, LendingIteratorItem};
 
 mod ext;
+#[cfg(feature = "fallible-streaming-iterator")]
+pub use ext::FallibleStreamingIteratorExt;
+pub use ext::GatLendingIteratorExt;
 pub use ext::IntoIteratorExt;
 pub use ext::IteratorExt;
+#[cfg(feature = "lender")]
+pub use ext::LenderExt;
 pub use ext::SliceExt;
+#[cfg(feature = "streaming-iterator")]
+pub use ext::StreamingIteratorExt;
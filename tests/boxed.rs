@@ -0,0 +1,91 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "alloc")]
+
+use hrtb_lending_iterator::*;
+
+#[test]
+fn test_boxed_ref_mut() {
+    let mut v = [0, 1, 2, 3, 4];
+    let mut w = [5, 6, 7];
+    let mut iters: Vec<BoxedLendingIterator<RefMutItem<[i32; 2]>>> = vec![
+        v.windows_mut::<2>().take(2).boxed::<RefMutItem<_>>(),
+        w.windows_mut::<2>().boxed::<RefMutItem<_>>(),
+    ];
+
+    let mut windows = vec![];
+    for iter in iters.iter_mut() {
+        while let Some(window) = iter.next() {
+            window[1] += 1;
+            windows.push(*window);
+        }
+    }
+    assert_eq!(windows, [[0, 2], [2, 3], [5, 7], [7, 8]]);
+    drop(iters);
+    assert_eq!(v, [0, 2, 3, 3, 4]);
+    assert_eq!(w, [5, 7, 8]);
+}
+
+#[test]
+fn test_boxed_ref() {
+    struct Chunks {
+        data: Vec<u8>,
+        buffer: Vec<u8>,
+        pos: usize,
+    }
+
+    impl<'any> LendingIteratorItem<'any> for Chunks {
+        type Type = &'any [u8];
+    }
+
+    impl LendingIterator for Chunks {
+        fn next(&mut self) -> Option<Item<'_, Self>> {
+            if self.pos >= self.data.len() {
+                return None;
+            }
+            let end = (self.pos + 2).min(self.data.len());
+            self.buffer.clear();
+            self.buffer.extend_from_slice(&self.data[self.pos..end]);
+            self.pos = end;
+            Some(&self.buffer)
+        }
+    }
+
+    fn source(flag: bool) -> BoxedLendingIterator<'static, RefItem<[u8]>> {
+        let chunks = Chunks {
+            data: vec![0, 1, 2, 3, 4],
+            buffer: vec![],
+            pos: 0,
+        };
+        if flag {
+            chunks.boxed::<RefItem<_>>()
+        } else {
+            chunks.take(1).boxed::<RefItem<_>>()
+        }
+    }
+
+    assert_eq!(
+        source(true).to_owned_item().collect::<Vec<_>>(),
+        [vec![0, 1], vec![2, 3], vec![4]]
+    );
+    assert_eq!(
+        source(false).to_owned_item().collect::<Vec<_>>(),
+        [vec![0, 1]]
+    );
+}
+
+#[test]
+fn test_boxed_owned() {
+    let iter: BoxedLendingIterator<OwnedItem<i32>> = [0, 1, 2]
+        .into_iter()
+        .into_lend_iter()
+        .boxed::<OwnedItem<_>>();
+    assert_eq!(
+        iter.map(|x| x * 2).into_iter().collect::<Vec<_>>(),
+        [0, 2, 4]
+    );
+}
@@ -0,0 +1,66 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "alloc")]
+
+use hrtb_lending_iterator::*;
+
+#[test]
+fn test_cartesian_product() {
+    let axes: [&[char]; 3] = [&['a', 'b'], &['x'], &['0', '1', '2']];
+    let c = cartesian_product_lend(&axes).collect::<Vec<Vec<_>>>();
+    assert_eq!(
+        c,
+        [
+            ['a', 'x', '0'],
+            ['a', 'x', '1'],
+            ['a', 'x', '2'],
+            ['b', 'x', '0'],
+            ['b', 'x', '1'],
+            ['b', 'x', '2']
+        ]
+    );
+
+    let mut iter = cartesian_product_lend(&axes);
+    let mut back = vec![];
+    while let Some(item) = iter.next_back() {
+        back.push(item.to_vec());
+    }
+    back.reverse();
+    assert_eq!(back, c);
+}
+
+#[test]
+fn test_cartesian_product_both_ends() {
+    let axes: [&[i32]; 2] = [&[0, 1, 2], &[3, 4]];
+    let mut iter = cartesian_product_lend(&axes);
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next(), Some(&[0, 3][..]));
+    assert_eq!(iter.next_back(), Some(&[2, 4][..]));
+    assert_eq!(iter.next_back(), Some(&[2, 3][..]));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(&[0, 4][..]));
+    assert_eq!(iter.next_back(), Some(&[1, 4][..]));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(&[1, 3][..]));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_cartesian_product_degenerate() {
+    let mut iter = cartesian_product_lend::<i32>(&[]);
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(&[][..]));
+    assert_eq!(iter.next(), None);
+
+    let axes: [&[i32]; 2] = [&[0, 1], &[]];
+    let mut iter = cartesian_product_lend(&axes);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
@@ -0,0 +1,84 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "alloc")]
+
+use hrtb_lending_iterator::*;
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+mod common;
+use common::lines;
+
+#[test]
+fn test_collect_vec() {
+    let v = lines("foo\nbar\nbaz").collect::<Vec<String>>();
+    assert_eq!(v, ["foo\n", "bar\n", "baz"]);
+
+    let v: Vec<String> = lines("").collect();
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_collect_string() {
+    let s = lines("foo\nbar\nbaz").take(2).collect::<String>();
+    assert_eq!(s, "foo\nbar\n");
+}
+
+/// Lends pairs from a slice through an internal buffer.
+struct Pairs<'a> {
+    pairs: &'a [(usize, char)],
+    buffer: (usize, char),
+}
+
+impl<'a, 'any> LendingIteratorItem<'any> for Pairs<'a> {
+    type Type = &'any (usize, char);
+}
+
+impl<'a> LendingIterator for Pairs<'a> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let (first, rest) = self.pairs.split_first()?;
+        self.pairs = rest;
+        self.buffer = *first;
+        Some(&self.buffer)
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_collect_hash_map() {
+    let v = [(0, 'a'), (1, 'b'), (0, 'c')];
+
+    let m = Pairs {
+        pairs: &v,
+        buffer: (0, ' '),
+    }
+    .collect::<HashMap<_, _>>();
+    assert_eq!(m, HashMap::from([(0, 'c'), (1, 'b')]));
+}
+
+#[test]
+fn test_collect_btree_map() {
+    let v = [(0, 'a'), (1, 'b'), (0, 'c')];
+
+    let m = Pairs {
+        pairs: &v,
+        buffer: (0, ' '),
+    }
+    .collect::<BTreeMap<_, _>>();
+    assert_eq!(m, BTreeMap::from([(0, 'c'), (1, 'b')]));
+}
+
+#[test]
+fn test_collect_windows_mut() {
+    let mut v = [0, 1, 2, 3];
+    let w = v.windows_mut::<2>().collect::<Vec<_>>();
+    assert_eq!(w, [[0, 1], [1, 2], [2, 3]]);
+
+    let m = v.windows_mut::<2>().enumerate().collect::<BTreeMap<_, _>>();
+    assert_eq!(m, BTreeMap::from([(0, [0, 1]), (1, [1, 2]), (2, [2, 3])]));
+}
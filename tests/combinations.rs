@@ -0,0 +1,67 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "alloc")]
+
+use hrtb_lending_iterator::*;
+
+#[test]
+fn test_combinations() {
+    let v = ['a', 'b', 'c', 'd'];
+    let c = v.combinations_lend(2).collect::<Vec<Vec<_>>>();
+    assert_eq!(
+        c,
+        [
+            ['a', 'b'],
+            ['a', 'c'],
+            ['a', 'd'],
+            ['b', 'c'],
+            ['b', 'd'],
+            ['c', 'd']
+        ]
+    );
+
+    let mut iter = v.combinations_lend(3);
+    let mut indices = vec![];
+    while let Some(c) = iter.next() {
+        assert_eq!(c.len(), 3);
+        indices.push(iter.indices().to_vec());
+    }
+    assert_eq!(indices, [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]);
+
+    assert_eq!(v.combinations_lend(4).collect::<Vec<Vec<_>>>(), [v]);
+    assert_eq!(v.combinations_lend(5).collect::<Vec<Vec<_>>>().len(), 0);
+    assert_eq!(v.combinations_lend(0).collect::<Vec<Vec<_>>>(), [[]; 1]);
+}
+
+#[test]
+fn test_combinations_with_replacement() {
+    let v = [0, 1, 2];
+    let c = v
+        .combinations_with_replacement_lend(2)
+        .collect::<Vec<Vec<_>>>();
+    assert_eq!(c, [[0, 0], [0, 1], [0, 2], [1, 1], [1, 2], [2, 2]]);
+    assert_eq!(
+        v.combinations_with_replacement_lend(4)
+            .collect::<Vec<Vec<_>>>()
+            .len(),
+        15
+    );
+
+    let e: [usize; 0] = [];
+    assert_eq!(
+        e.combinations_with_replacement_lend(1)
+            .collect::<Vec<Vec<_>>>()
+            .len(),
+        0
+    );
+    assert_eq!(
+        e.combinations_with_replacement_lend(0)
+            .collect::<Vec<Vec<_>>>()
+            .len(),
+        1
+    );
+}
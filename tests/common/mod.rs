@@ -0,0 +1,42 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Fixtures shared by the integration tests.
+
+// Each test crate includes this module, but not every crate uses all fixtures.
+#![allow(dead_code)]
+
+use hrtb_lending_iterator::*;
+use std::io::BufRead;
+
+/// Lends the lines of a reader, including the line terminator, reusing
+/// an internal buffer.
+pub struct Lines<B: BufRead> {
+    reader: B,
+    buffer: String,
+}
+
+impl<'any, B: BufRead> LendingIteratorItem<'any> for Lines<B> {
+    type Type = &'any str;
+}
+
+impl<B: BufRead> LendingIterator for Lines<B> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        self.buffer.clear();
+        if self.reader.read_line(&mut self.buffer).unwrap() == 0 {
+            return None;
+        }
+        Some(&self.buffer)
+    }
+}
+
+/// Returns a [`Lines`] lending the lines of a string.
+pub fn lines(s: &str) -> Lines<&[u8]> {
+    Lines {
+        reader: s.as_bytes(),
+        buffer: String::new(),
+    }
+}
@@ -0,0 +1,45 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "alloc")]
+
+use hrtb_lending_iterator::*;
+
+mod common;
+use common::lines;
+
+#[test]
+fn test_dedup() {
+    let v = lines("a\na\nb\nb\nb\na\n").dedup().collect::<Vec<String>>();
+    assert_eq!(v, ["a\n", "b\n", "a\n"]);
+
+    let mut v = [0, 0, 0, 1, 1];
+    let w = v.windows_mut::<2>().dedup().collect::<Vec<_>>();
+    assert_eq!(w, [[0, 0], [0, 1], [1, 1]]);
+}
+
+#[test]
+fn test_dedup_by() {
+    let v = lines("a\nA\nb\nB\na\n")
+        .dedup_by(|prev: &String, line: &&str| prev.eq_ignore_ascii_case(line))
+        .collect::<Vec<String>>();
+    assert_eq!(v, ["a\n", "b\n", "a\n"]);
+}
+
+#[test]
+fn test_dedup_by_key() {
+    let v = lines("a\nbb\ncc\nd\ne\n")
+        .dedup_by_key(|line: &&str| line.len())
+        .collect::<Vec<String>>();
+    assert_eq!(v, ["a\n", "bb\n", "d\n"]);
+
+    let mut v = [0, 2, 0, 1, 3];
+    let w = v
+        .windows_mut::<2>()
+        .dedup_by_key(|[a, b]: &&mut [i32; 2]| *a + *b)
+        .collect::<Vec<_>>();
+    assert_eq!(w, [[0, 2], [0, 1], [1, 3]]);
+}
@@ -0,0 +1,56 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use hrtb_lending_iterator::*;
+
+fn windows(
+    v: &mut [usize],
+    all: bool,
+) -> impl ExactSizeLendingIterator + for<'any> LendingIteratorItem<'any, Type = &'any mut [usize; 2]> + '_
+{
+    if all {
+        Either::Left(v.windows_mut::<2>())
+    } else {
+        Either::Right(v.windows_mut::<2>().take(2))
+    }
+}
+
+#[test]
+fn test_either() {
+    let mut v = [0, 1, 2, 3, 4];
+
+    let iter = windows(&mut v, true);
+    assert_eq!(iter.len(), 4);
+    assert_eq!(
+        iter.cloned().collect::<Vec<_>>(),
+        [[0, 1], [1, 2], [2, 3], [3, 4]]
+    );
+
+    let mut iter = windows(&mut v, false);
+    assert_eq!(iter.len(), 2);
+    while let Some(w) = iter.next() {
+        w[1] += w[0];
+    }
+    drop(iter);
+    assert_eq!(v, [0, 1, 3, 3, 4]);
+}
+
+#[test]
+fn test_either_double_ended() {
+    let v = [0, 1, 2, 3];
+    for rev in [false, true] {
+        let mut iter = if rev {
+            Either::Left(v.iter().rev().into_lend_iter())
+        } else {
+            Either::Right(v.iter().into_lend_iter())
+        };
+        assert_eq!(iter.len(), 4);
+        let (first, last) = if rev { (&3, &0) } else { (&0, &3) };
+        assert_eq!(iter.next_back(), Some(last));
+        assert_eq!(iter.next(), Some(first));
+        assert_eq!(iter.len(), 2);
+    }
+}
@@ -0,0 +1,20 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "fallible-streaming-iterator")]
+
+use hrtb_lending_iterator::*;
+
+#[test]
+fn test_from_fallible_streaming_iter() {
+    let v = [Ok(&0), Err("error"), Ok(&1), Ok(&2)];
+    let mut iter = fallible_streaming_iterator::convert(v.into_iter()).into_lend_iter();
+    assert_eq!(iter.next(), Some(Ok(&0)));
+    assert_eq!(iter.next(), Some(Err("error")));
+    assert_eq!(iter.next_back(), Some(Ok(&2)));
+    assert_eq!(iter.next(), Some(Ok(&1)));
+    assert_eq!(iter.next(), None);
+}
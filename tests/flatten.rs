@@ -0,0 +1,118 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use hrtb_lending_iterator::*;
+
+/// Lends, for each group of pairs with the same first coordinate, an
+/// iterator on the second coordinates, which are copied into an internal buffer.
+struct Groups<'a> {
+    pairs: &'a [(usize, u32)],
+    buffer: Vec<u32>,
+}
+
+/// Lends mutable references to the records of a group.
+struct Group<'a> {
+    records: &'a mut [u32],
+}
+
+impl<'a, 'any> LendingIteratorItem<'any> for Group<'a> {
+    type Type = &'any mut u32;
+}
+
+impl<'a> LendingIterator for Group<'a> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let (first, rest) = core::mem::take(&mut self.records).split_first_mut()?;
+        self.records = rest;
+        Some(first)
+    }
+}
+
+impl<'a, 'any> LendingIteratorItem<'any> for Groups<'a> {
+    type Type = Group<'any>;
+}
+
+impl<'a> LendingIterator for Groups<'a> {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let key = self.pairs.first()?.0;
+        let len = self.pairs.iter().take_while(|(k, _)| *k == key).count();
+        self.buffer.clear();
+        self.buffer
+            .extend(self.pairs[..len].iter().map(|(_, record)| *record));
+        self.pairs = &self.pairs[len..];
+        Some(Group {
+            records: &mut self.buffer,
+        })
+    }
+}
+
+fn groups(pairs: &[(usize, u32)]) -> Groups<'_> {
+    Groups {
+        pairs,
+        buffer: vec![],
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_flatten() {
+    let pairs = [(0, 1), (0, 2), (1, 3), (2, 4), (2, 5)];
+    let mut iter = groups(&pairs).flatten::<RefMutItem<_>>();
+    let mut records = vec![];
+    while let Some(record) = iter.next() {
+        *record += 1;
+        records.push(*record);
+    }
+    assert_eq!(records, [2, 3, 4, 5, 6]);
+
+    let mut iter = groups(&pairs).flatten::<RefMutItem<_>>();
+    assert_eq!(iter.next(), Some(&mut 1));
+    // The inner iterator borrows from the outer one, so it must survive a move.
+    let mut iter = *Box::new(iter);
+    assert_eq!(iter.next(), Some(&mut 2));
+    assert_eq!(iter.next(), Some(&mut 3));
+
+    assert_eq!(groups(&[]).flatten::<RefMutItem<_>>().next(), None);
+}
+
+#[test]
+fn test_flat_map() {
+    let pairs = [(0, 1), (0, 2), (1, 3), (2, 4), (2, 5)];
+    let iter = groups(&pairs).flat_map(|group| {
+        group
+            .map(|r: &mut u32| *r * 10)
+            .into_iter()
+            .collect::<Vec<_>>()
+            .into_into_lend_iter()
+    });
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [10, 20, 30, 40, 50]);
+
+    let mut v = [0, 1, 2, 3];
+    let iter = v
+        .windows_mut::<2>()
+        .flat_map(|[a, b]| (*a..*b + 1).into_into_lend_iter());
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [0, 1, 1, 2, 2, 3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_flatten_slices() {
+    let mut v = [0, 1, 2, 3];
+    let mut iter = v.windows_mut::<2>().flatten::<RefMutItem<_>>();
+    while let Some(x) = iter.next() {
+        *x += 1;
+    }
+    drop(iter);
+    // Inner elements are visited once for each window containing them.
+    assert_eq!(v, [1, 3, 4, 4]);
+
+    let v = [1, 2, 3, 4, 5];
+    let iter = v.chunks(2).into_lend_iter().flatten::<RefItem<_>>();
+    assert_eq!(iter.fold(0, |sum, x| sum + x), 15);
+
+    let v = [vec![0, 1], vec![], vec![2]];
+    let iter = v.iter().into_lend_iter().flatten::<RefItem<_>>();
+    assert_eq!(iter.copied().collect::<Vec<_>>(), [0, 1, 2]);
+}
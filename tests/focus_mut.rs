@@ -0,0 +1,43 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use hrtb_lending_iterator::*;
+
+#[test]
+fn test_focus_mut() {
+    // In-place prefix sums
+    let mut v = [1, 2, 3, 4, 5];
+    for_lend! {f in v.focus_mut() =>
+        let Focus { left, focus, right } = f;
+        assert_eq!(left.len() + right.len(), 4);
+        *focus += left.last().copied().unwrap_or(0);
+    }
+    assert_eq!(v, [1, 3, 6, 10, 15]);
+
+    // In-place suffix sums, from the back
+    let mut v = [1, 2, 3, 4];
+    let mut iter = v.focus_mut();
+    while let Some(Focus { focus, right, .. }) = iter.next_back() {
+        *focus += right.first().copied().unwrap_or(0);
+    }
+    assert_eq!(v, [10, 9, 7, 4]);
+}
+
+#[test]
+fn test_focus_mut_len() {
+    let mut v = [0, 1, 2, 3];
+    let mut iter = v.focus_mut();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(*iter.next().unwrap().focus, 0);
+    assert_eq!(*iter.next_back().unwrap().focus, 3);
+    assert_eq!(iter.len(), 2);
+    let f = iter.next_back().unwrap();
+    assert_eq!((&*f.left, *f.focus, &*f.right), (&[0, 1][..], 2, &[3][..]));
+    assert_eq!(*iter.next().unwrap().focus, 1);
+    assert_eq!(iter.len(), 0);
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+}
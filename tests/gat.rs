@@ -0,0 +1,95 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use hrtb_lending_iterator::*;
+
+/// Mutable windows of size two written using the GAT-based design.
+struct GatWindowsMut<'a, T> {
+    slice: &'a mut [T],
+    curr_pos: usize,
+}
+
+impl<'a, T> GatLendingIterator for GatWindowsMut<'a, T> {
+    type Item<'b>
+        = &'b mut [T]
+    where
+        Self: 'b;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let window = self.slice.get_mut(self.curr_pos..)?.get_mut(..2)?;
+        self.curr_pos += 1;
+        Some(window)
+    }
+}
+
+#[test]
+fn test_from_gat() {
+    let mut v = [0, 1, 2, 3];
+    let iter = GatWindowsMut {
+        slice: &mut v,
+        curr_pos: 0,
+    };
+    for_lend! {window in iter =>
+        window[1] += window[0];
+    };
+    assert_eq!(v, [0, 1, 3, 6]);
+
+    let mut iter = GatWindowsMut {
+        slice: &mut v,
+        curr_pos: 0,
+    }
+    .into_lend_iter()
+    .enumerate()
+    .take(2);
+    assert_eq!(iter.next(), Some((0, &mut [0, 1][..])));
+    assert_eq!(iter.next(), Some((1, &mut [1, 3][..])));
+    assert_eq!(iter.next(), None);
+}
+
+/// Lends the elements of an owned vector through an internal buffer.
+struct GatBuffered {
+    v: Vec<String>,
+    buffer: String,
+}
+
+impl GatLendingIterator for GatBuffered {
+    type Item<'b> = &'b str;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.buffer = self.v.pop()?;
+        Some(&self.buffer)
+    }
+}
+
+#[test]
+fn test_from_gat_static() {
+    let iter = GatBuffered {
+        v: vec!["a".to_owned(), "bb".to_owned()],
+        buffer: String::new(),
+    };
+    let lens = iter
+        .into_lend_iter()
+        .map(|s: &str| s.len())
+        .into_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(lens, [2, 1]);
+}
+
+#[test]
+fn test_into_gat() {
+    let mut v = [0, 1, 2, 3];
+    let mut iter = v.windows_mut::<2>().into_gat();
+    while let Some(window) = GatLendingIterator::next(&mut iter) {
+        window[1] += window[0];
+    }
+
+    let mut iter = iter.into_inner();
+    assert_eq!(iter.next(), None);
+    assert_eq!(v, [0, 1, 3, 6]);
+
+    let mut iter = v.windows_mut::<2>().into_gat().into_lend_iter();
+    assert_eq!(iter.next(), Some(&mut [0, 1]));
+}
@@ -0,0 +1,55 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "lender")]
+
+use hrtb_lending_iterator::*;
+use lender::{DoubleEndedLender, ExactSizeLender, Lender};
+
+#[test]
+fn test_into_lender() {
+    let mut v = [0, 1, 2, 3, 4];
+    let mut lender = v.windows_mut::<2>().into_lender::<RefMutItem<_>>();
+    assert_eq!(ExactSizeLender::len(&lender), 4);
+    while let Some(w) = Lender::next(&mut lender) {
+        w[1] += w[0];
+    }
+    assert_eq!(v, [0, 1, 3, 6, 10]);
+
+    let v = [0, 1, 2, 3];
+    let mut lender = v.iter().into_lend_iter().into_lender::<OwnedItem<_>>();
+    assert_eq!(DoubleEndedLender::next_back(&mut lender), Some(&3));
+    assert_eq!(Lender::next(&mut lender), Some(&0));
+    assert_eq!(ExactSizeLender::len(&lender), 2);
+}
+
+#[test]
+fn test_from_lender() {
+    let mut v = [0, 1, 2, 3];
+    let mut iter = lender::windows_mut(&mut v, 2).into_lend_iter().enumerate();
+    while let Some((i, w)) = LendingIterator::next(&mut iter) {
+        w[0] += i;
+    }
+    assert_eq!(v, [0, 2, 4, 3]);
+
+    let v = [0, 1, 2, 3];
+    let mut iter = lender::from_iter(v.iter()).into_lend_iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(LendingIterator::next(&mut iter), Some(&0));
+}
+
+#[test]
+fn test_round_trip() {
+    let mut v = [0, 1, 2];
+    let iter = v
+        .windows_mut::<2>()
+        .into_lender::<RefMutItem<_>>()
+        .into_lend_iter()
+        .into_inner()
+        .into_inner();
+    assert_eq!(iter.cloned().collect::<Vec<_>>(), [[0, 1], [1, 2]]);
+}
@@ -6,6 +6,8 @@
 
 use hrtb_lending_iterator::*;
 
+mod common;
+
 #[test]
 fn test_take() {
     let v = [0, 1, 2, 3, 4];
@@ -21,6 +23,24 @@ fn test_take() {
     assert_eq!(iter.take(5).into_iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
 }
 
+#[test]
+fn test_by_ref() {
+    let mut v = [0, 1, 2, 3, 4];
+    let mut iter = v.windows_mut::<2>();
+    assert_eq!(
+        iter.by_ref().take(2).cloned().collect::<Vec<_>>(),
+        [[0, 1], [1, 2]]
+    );
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(&mut [2, 3]));
+
+    let mut iter = v.iter().into_lend_iter();
+    iter.by_ref().take(1).for_each(|_| {});
+    assert_eq!(iter.by_ref().take(10).len(), 4);
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [&1, &2, &3]);
+}
+
 #[test]
 fn test_inspect() {
     let v = [0, 1, 2, 3, 4];
@@ -30,6 +50,21 @@ fn test_inspect() {
     assert_eq!(c, 5);
 }
 
+#[test]
+fn test_inspect_mut() {
+    let mut v = [3, 1, 2, 0];
+    let iter = v.windows_mut::<2>().inspect_mut(|window| window.sort());
+    assert_eq!(iter.cloned().collect::<Vec<_>>(), [[1, 3], [2, 3], [0, 3]]);
+    assert_eq!(v, [1, 2, 0, 3]);
+
+    let v = [0, 1, 2];
+    let iter = v
+        .into_into_lend_iter()
+        .into_lend_iter()
+        .inspect_mut(|x| *x *= 2);
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [0, 2, 4]);
+}
+
 #[test]
 fn test_map() {
     let v = [0, 1, 2, 3, 4];
@@ -47,42 +82,278 @@ fn test_take_while() {
     assert_eq!(iter.into_iter().collect::<Vec<_>>(), [0, 1, 2]);
 }
 
+#[test]
+fn test_scan() {
+    let mut v = [1, 2, 3, 4];
+    let iter = v.windows_mut::<2>().scan(0, |offset, window| {
+        *offset += window[0];
+        window[1] += *offset;
+        Some(*offset)
+    });
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [1, 4, 11]);
+    assert_eq!(v, [1, 3, 7, 15]);
+
+    let v = [0, 1, 2, 3, 4];
+    let iter = v.into_into_lend_iter().into_lend_iter().scan(0, |sum, x| {
+        *sum += x;
+        (*sum < 5).then_some(*sum)
+    });
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [0, 1, 3]);
+}
+
+#[test]
+fn test_map_while() {
+    let mut v = [0, 1, 2, 3, 4];
+    let iter = v
+        .windows_mut::<2>()
+        .map_while(|[a, b]| (*b < 3).then_some(*a + *b));
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [1, 3]);
+}
+
+#[test]
+fn test_intersperse() {
+    let v = [0, 1, 2];
+    let iter = v.into_into_lend_iter().into_lend_iter().intersperse(10);
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [0, 10, 1, 10, 2]);
+
+    let mut v = [0, 1, 2, 3];
+    let mut c = 0;
+    let iter = v
+        .windows_mut::<2>()
+        .map(|[a, b]| *a + *b)
+        .intersperse_with(|| {
+            c -= 1;
+            c
+        });
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [1, -1, 3, -2, 5]);
+
+    let iter = [0].into_into_lend_iter().into_lend_iter().intersperse(10);
+    assert_eq!(iter.into_iter().collect::<Vec<_>>(), [0]);
+    let iter = [0; 0]
+        .into_into_lend_iter()
+        .into_lend_iter()
+        .intersperse(10);
+    assert_eq!(iter.into_iter().count(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_sum_product() {
+    let v = [1, 2, 3, 4];
+    assert_eq!(v.into_into_lend_iter().into_lend_iter().sum::<i32>(), 10);
+    assert_eq!(v.iter().into_lend_iter().product::<i32>(), 24);
+    assert_eq!(v.iter().into_lend_iter().take(0).sum::<i32>(), 0);
+    assert_eq!(v.iter().into_lend_iter().take(0).product::<i32>(), 1);
+
+    let mut v = [1u64, 2, 3, 4];
+    let sum = v
+        .windows_mut::<2>()
+        .take(2)
+        .map(|[a, b]| *a * *b)
+        .sum::<u64>();
+    assert_eq!(sum, 8);
+
+    // Items lent by reference
+    let v = [1.5, 2.5, 3.];
+    assert_eq!(v.as_slice().into_lend_iter().sum::<f64>(), 7.);
+    assert_eq!(v.as_slice().into_lend_iter().take(2).product::<f64>(), 3.75);
+}
+
+#[test]
+fn test_sum_by() {
+    let mut v = [1u64, 2, 3, 4];
+    let sum = v.windows_mut::<3>().sum_by(|w| w.iter().sum::<u64>());
+    assert_eq!(sum, 15);
+    let sum = v.windows_mut::<2>().take(1).sum_by(|w| w[0] as f64 / 2.);
+    assert_eq!(sum, 0.5);
+}
+
+#[test]
+fn test_eq() {
+    let mut v = [0, 1, 2];
+    assert!(v
+        .windows_mut::<2>()
+        .eq([[0, 1], [1, 2]].iter().into_into_lend_iter()));
+    assert!(v
+        .windows_mut::<2>()
+        .ne([[0, 1]].iter().into_into_lend_iter()));
+    assert!(v
+        .windows_mut::<2>()
+        .ne([[0, 1], [1, 3]].iter().into_into_lend_iter()));
+
+    let mut w = [0, 1, 2];
+    assert!(v.windows_mut::<2>().eq(w.windows_mut::<2>()));
+    assert!(v
+        .windows_mut::<2>()
+        .eq_by(["01", "12"].into_into_lend_iter(), |w, s| {
+            w.iter().map(|x| x.to_string()).collect::<String>() == *s
+        }));
+}
+
+#[test]
+fn test_cmp() {
+    use std::cmp::Ordering;
+
+    let mut v = [0, 1, 2];
+    let mut w = [0, 1, 3];
+    assert!(v.windows_mut::<2>().lt(w.windows_mut::<2>()));
+    assert!(v.windows_mut::<2>().le(w.windows_mut::<2>()));
+    assert!(w.windows_mut::<2>().gt(v.windows_mut::<2>()));
+    assert!(w.windows_mut::<2>().ge(v.windows_mut::<2>()));
+    let mut u = [0, 1, 2];
+    assert!(v.windows_mut::<2>().le(u.windows_mut::<2>()));
+    assert!(v.windows_mut::<2>().ge(u.windows_mut::<2>()));
+    assert!(v.windows_mut::<2>().lt(w.windows_mut::<2>().take(3)));
+    assert!(v.windows_mut::<2>().gt(w.windows_mut::<2>().take(1)));
+
+    assert_eq!(
+        v.windows_mut::<2>()
+            .cmp_by([1, 2].into_into_lend_iter(), |w, x| w[1].cmp(x)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        v.windows_mut::<2>()
+            .cmp_by([1].into_into_lend_iter(), |w, x| w[1].cmp(x)),
+        Ordering::Greater
+    );
+
+    let x = [0., f64::NAN];
+    assert_eq!(
+        x.iter()
+            .into_lend_iter()
+            .partial_cmp([0., 1.].iter().into_into_lend_iter()),
+        None
+    );
+    assert_eq!(
+        x.iter()
+            .into_lend_iter()
+            .partial_cmp_by([1., 0.].into_into_lend_iter(), |a, b| a.partial_cmp(&b)),
+        Some(Ordering::Less)
+    );
+}
+
+#[test]
+fn test_max_min_by_key() {
+    let mut v = [3, 1, 4, 1, 5, 9, 2, 6];
+    let max = v.windows_mut::<3>().max_by_key(|w| w.iter().sum::<i32>());
+    assert_eq!(max, Some(17));
+    let min = v.windows_mut::<3>().min_by_key(|w| w.iter().sum::<i32>());
+    assert_eq!(min, Some(6));
+    assert_eq!(v.windows_mut::<9>().max_by_key(|w| w[0]), None);
+
+    let text = "a bbb cc ddd";
+    let longest = text.split(' ').into_lend_iter().max_by_key(|w| w.len());
+    assert_eq!(longest, Some(3));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_max_by_owned() {
+    let mut v = [3, 1, 4, 1, 5, 9, 2, 6];
+    let max = v
+        .windows_mut::<3>()
+        .max_by_owned(|w, max| w.iter().sum::<i32>().cmp(&max.iter().sum()));
+    assert_eq!(max, Some([9, 2, 6]));
+
+    let text = "a bbb cc ddd";
+    let longest = text
+        .split(' ')
+        .into_lend_iter()
+        .max_by_owned(|w, max: &String| w.len().cmp(&max.len()));
+    assert_eq!(longest.as_deref(), Some("ddd"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_last_owned() {
+    let mut v = [0, 1, 2, 3];
+    assert_eq!(v.windows_mut::<2>().last_owned(), Some([2, 3]));
+    assert_eq!(v.windows_mut::<5>().last_owned(), None);
+}
+
+#[test]
+fn test_reduce() {
+    let v = [1, 2, 3, 4];
+    let iter = v.into_into_lend_iter().into_lend_iter();
+    assert_eq!(iter.reduce(|a, b| a * b), Some(24));
+
+    let mut v = [1, 2, 3];
+    let iter = v.windows_mut::<2>().map(|[a, b]| *a + *b);
+    assert_eq!(iter.reduce(i32::max), Some(5));
+    assert_eq!(
+        [0; 0]
+            .into_into_lend_iter()
+            .into_lend_iter()
+            .reduce(|a, b| a + b),
+        None
+    );
+}
+
+#[cfg(feature = "alloc")]
 #[test]
 fn test_to_owned_item_slice() {
     let v = [0, 1, 2, 3, 4];
     let mut iter = v.windows(2).into_lend_iter().to_owned_item();
-    //let a = iter.next();
-    //let b = iter.next();
+    let a = iter.next();
+    let b = iter.next();
+    assert_eq!(a, Some(vec![0, 1]));
+    assert_eq!(b, Some(vec![1, 2]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_to_owned_item_windows_mut() {
+    let mut v = [0, 1, 2, 3, 4];
+    let iter = v.windows_mut::<3>().to_owned_item();
+    assert_eq!(iter.collect::<Vec<_>>(), [[0, 1, 2], [1, 2, 3], [2, 3, 4]]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_to_owned_item_enumerate() {
+    let mut v = [0, 1, 2, 3];
+    let iter = v.windows_mut::<2>().enumerate().to_owned_item();
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        [(0, [0, 1]), (1, [1, 2]), (2, [2, 3])]
+    );
+}
+
+#[test]
+fn test_cloned() {
+    let mut v = [0, 1, 2, 3];
+    let iter = v.windows_mut::<3>().cloned();
+    assert_eq!(iter.collect::<Vec<_>>(), [[0, 1, 2], [1, 2, 3]]);
+
+    let v = [vec![0], vec![1, 2]];
+    let iter = v.iter().into_lend_iter().cloned();
+    assert_eq!(iter.collect::<Vec<_>>(), v);
+}
+
+#[test]
+fn test_copied() {
+    let mut v = [0, 1, 2, 3];
+    let sum: usize = v.windows_mut::<2>().copied().map(|[a, b]| a * b).sum();
+    assert_eq!(sum, 8);
+
+    let v = [0, 1, 2];
+    let iter = v.iter().into_lend_iter().copied();
+    assert_eq!(iter.collect::<Vec<_>>(), v);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_into_owned_item() {
+    let mut x = 1;
+    assert_eq!(Some(&x).into_owned_item(), Some(1));
+    assert_eq!((0, &mut x, "a").into_owned_item(), (0, 1, "a".to_owned()));
+    assert_eq!(None::<&str>.into_owned_item(), None);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn test_to_owned_item_string() {
-    use std::io::BufRead;
-
-    struct Lines<B: BufRead> {
-        reader: B,
-        buffer: String,
-    }
-
-    impl<'any, B: BufRead> LendingIteratorItem<'any> for Lines<B> {
-        type Type = &'any str;
-    }
-
-    impl<R: BufRead> LendingIterator for Lines<R> {
-        fn next(&mut self) -> Option<Item<'_, Self>> {
-            self.buffer.clear();
-            if self.reader.read_line(&mut self.buffer).unwrap() == 0 {
-                return None;
-            }
-            Some(&self.buffer)
-        }
-    }
-
-    let mut iter = Lines {
-        reader: "foo\nbar\nbaz".as_ref(),
-        buffer: String::new(),
-    }
-    .to_owned_item();
+    let mut iter = common::lines("foo\nbar\nbaz").to_owned_item();
     let a = iter.next();
     let b = iter.next();
     let c = iter.next();
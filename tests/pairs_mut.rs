@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use hrtb_lending_iterator::*;
+
+#[test]
+fn test_pairs_mut() {
+    let mut v = [(0, 0); 4];
+    let mut pairs = vec![];
+    for_lend! {p in v.pairs_mut() =>
+        let (a, b) = p;
+        a.1 += 1;
+        b.0 += 1;
+        pairs.push((a.1, b.0));
+    }
+    // Each element is the first element of a pair as many times as
+    // there are larger indices, and the second one as many times
+    // as there are smaller indices.
+    assert_eq!(v, [(0, 3), (1, 2), (2, 1), (3, 0)]);
+    assert_eq!(pairs, [(1, 1), (2, 1), (3, 1), (1, 2), (2, 2), (1, 3)]);
+}
+
+#[test]
+fn test_pairs_mut_len() {
+    let mut v = [0, 1, 2, 3, 4];
+    let mut iter = v.pairs_mut();
+    let mut len = 10;
+    assert_eq!(iter.len(), len);
+    while iter.next().is_some() {
+        len -= 1;
+        assert_eq!(iter.len(), len);
+    }
+    assert_eq!(len, 0);
+
+    assert_eq!([0].pairs_mut().len(), 0);
+    assert!([0].pairs_mut().next().is_none());
+    assert_eq!(<[i32]>::pairs_mut(&mut []).len(), 0);
+    assert!(<[i32]>::pairs_mut(&mut []).next().is_none());
+}
+
+#[test]
+fn test_ordered_pairs_mut() {
+    let mut v = [0, 1, 2];
+    let mut pairs = vec![];
+    for_lend! {p in v.ordered_pairs_mut() =>
+        let (a, b) = p;
+        pairs.push((*a, *b));
+    }
+    assert_eq!(pairs, [(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]);
+
+    // Each element receives the sum of the others
+    let mut v = [(1, 0), (2, 0), (3, 0)];
+    for_lend! {p in v.ordered_pairs_mut() =>
+        let (a, b) = p;
+        a.1 += b.0;
+    }
+    assert_eq!(v, [(1, 5), (2, 4), (3, 3)]);
+
+    let mut v = [0, 1, 2, 3, 4];
+    let mut iter = v.ordered_pairs_mut();
+    let mut len = 20;
+    assert_eq!(iter.len(), len);
+    while iter.next().is_some() {
+        len -= 1;
+        assert_eq!(iter.len(), len);
+    }
+    assert_eq!(len, 0);
+
+    assert_eq!([0].ordered_pairs_mut().len(), 0);
+    assert!([0].ordered_pairs_mut().next().is_none());
+    assert!(<[i32]>::ordered_pairs_mut(&mut []).next().is_none());
+}
@@ -0,0 +1,103 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "alloc")]
+
+use hrtb_lending_iterator::*;
+
+#[test]
+fn test_partitions() {
+    let p = partitions_lend(5).collect::<Vec<Vec<_>>>();
+    assert_eq!(
+        p,
+        [
+            vec![5],
+            vec![4, 1],
+            vec![3, 2],
+            vec![3, 1, 1],
+            vec![2, 2, 1],
+            vec![2, 1, 1, 1],
+            vec![1, 1, 1, 1, 1]
+        ]
+    );
+    // The number of partitions of 20
+    assert_eq!(partitions_lend(20).fold(0, |c, _| c + 1), 627);
+    assert_eq!(partitions_lend(0).collect::<Vec<Vec<_>>>(), [vec![]]);
+    assert_eq!(partitions_lend(1).collect::<Vec<Vec<_>>>(), [vec![1]]);
+}
+
+#[test]
+fn test_compositions() {
+    let c = compositions_lend(5, 3).collect::<Vec<Vec<_>>>();
+    assert_eq!(
+        c,
+        [
+            [1, 1, 3],
+            [1, 2, 2],
+            [1, 3, 1],
+            [2, 1, 2],
+            [2, 2, 1],
+            [3, 1, 1]
+        ]
+    );
+
+    let mut iter = compositions_lend(10, 4);
+    assert_eq!(iter.len(), 84);
+    let mut count = 0;
+    while let Some(c) = iter.next() {
+        assert_eq!(c.iter().sum::<usize>(), 10);
+        count += 1;
+        assert_eq!(iter.len(), 84 - count);
+    }
+    assert_eq!(count, 84);
+
+    // The partial results of the binomial coefficient must not overflow
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(compositions_lend(67, 34).len(), 7219428434016265740);
+        assert_eq!(compositions_lend(67, 33).len(), 7007092303604022630);
+    }
+
+    assert_eq!(compositions_lend(3, 1).collect::<Vec<Vec<_>>>(), [[3]]);
+    assert_eq!(
+        compositions_lend(3, 3).collect::<Vec<Vec<_>>>(),
+        [[1, 1, 1]]
+    );
+    assert_eq!(compositions_lend(0, 0).collect::<Vec<Vec<_>>>(), [vec![]]);
+    assert_eq!(compositions_lend(2, 3).len(), 0);
+    assert_eq!(compositions_lend(2, 3).next(), None);
+    assert_eq!(compositions_lend(2, 0).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_compositions_len_overflow() {
+    compositions_lend(69, 35).len();
+}
+
+#[test]
+fn test_gray_code_subsets() {
+    let mut iter = gray_code_subsets_lend(4);
+    assert_eq!(iter.len(), 16);
+    let mut seen = vec![];
+    let mut prev: Option<Vec<bool>> = None;
+    while let Some(subset) = iter.next() {
+        let subset = subset.to_vec();
+        if let Some(prev) = prev {
+            let diff = (0..4).filter(|&i| prev[i] != subset[i]).collect::<Vec<_>>();
+            assert_eq!(diff, [iter.flipped().unwrap()]);
+        }
+        seen.push(subset.iter().rev().fold(0, |x, &b| x * 2 + b as usize));
+        prev = Some(subset);
+    }
+    assert_eq!(iter.len(), 0);
+    seen.sort_unstable();
+    assert_eq!(seen, (0..16).collect::<Vec<_>>());
+
+    let mut iter = gray_code_subsets_lend(0);
+    assert_eq!(iter.next(), Some(&[][..]));
+    assert_eq!(iter.next(), None);
+}
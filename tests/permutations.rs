@@ -0,0 +1,79 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "alloc")]
+
+use hrtb_lending_iterator::*;
+
+#[test]
+fn test_permutations() {
+    let mut v = [0, 1, 2];
+    let mut p = v.permutations_lend().collect::<Vec<Vec<_>>>();
+    assert_eq!(p[0], [0, 1, 2]);
+    p.sort();
+    assert_eq!(
+        p,
+        [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0]
+        ]
+    );
+
+    let mut v = [0, 1, 2, 3, 4];
+    let mut p = v.permutations_lend().collect::<Vec<Vec<_>>>();
+    p.sort();
+    p.dedup();
+    assert_eq!(p.len(), 120);
+}
+
+#[test]
+fn test_permutations_owned() {
+    let mut v = [0, 1, 2, 3];
+    let expected = v.permutations_lend().collect::<Vec<Vec<_>>>();
+    let mut iter = permutations_lend(vec![0, 1, 2, 3]);
+    assert_eq!(iter.len(), 24);
+    let mut p = vec![];
+    while let Some(perm) = iter.next() {
+        p.push(perm.to_vec());
+    }
+    assert_eq!(p, expected);
+    // Both buffers contain the last permutation
+    assert_eq!(iter.into_inner(), v);
+
+    let iter = permutations_lend(Box::from([0, 1]));
+    assert_eq!(iter.collect::<Vec<Vec<_>>>(), [[0, 1], [1, 0]]);
+}
+
+#[test]
+fn test_permutations_small() {
+    let mut v: [usize; 0] = [];
+    assert_eq!(v.permutations_lend().collect::<Vec<Vec<_>>>(), [[]]);
+    let mut v = [0];
+    assert_eq!(v.permutations_lend().collect::<Vec<Vec<_>>>(), [[0]]);
+}
+
+#[test]
+fn test_permutations_len() {
+    let mut v = [0, 1, 2, 3];
+    let mut iter = v.permutations_lend();
+    assert_eq!(iter.len(), 24);
+    iter.next();
+    iter.next();
+    assert_eq!(iter.len(), 22);
+    while iter.next().is_some() {}
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_permutations_len_overflow() {
+    let mut v = [0; 40];
+    v.permutations_lend().len();
+}
@@ -0,0 +1,109 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "stream")]
+
+use hrtb_lending_iterator::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+mod common;
+use common::lines;
+
+/// Returns a waker that does nothing when woken.
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    // SAFETY: the vtable functions ignore the data pointer.
+    unsafe { Waker::from_raw(clone(std::ptr::null())) }
+}
+
+/// A minimal executor busy-polling a future.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+/// A stream returning the items of a lending iterator, returning
+/// [`Poll::Pending`] before each item.
+struct Pending<I> {
+    iter: I,
+    ready: bool,
+}
+
+impl<I> Pending<I> {
+    fn new(iter: I) -> Self {
+        Pending { iter, ready: false }
+    }
+}
+
+impl<'any, I: LendingIterator> LendingIteratorItem<'any> for Pending<I> {
+    type Type = Item<'any, I>;
+}
+
+impl<I: LendingIterator + Unpin> LendingStream for Pending<I> {
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Item<'_, Self>>> {
+        let this = self.get_mut();
+        if !this.ready {
+            this.ready = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        this.ready = false;
+        Poll::Ready(this.iter.next())
+    }
+}
+
+#[test]
+fn test_next() {
+    let lines = block_on(async {
+        let mut stream = Pending::new(lines("foo\nbar\nbaz"));
+        let mut lines = vec![];
+        while let Some(line) = stream.next().await {
+            lines.push(line.to_owned());
+        }
+        lines
+    });
+    assert_eq!(lines, ["foo\n", "bar\n", "baz"]);
+}
+
+#[test]
+fn test_adapters() {
+    let lengths = block_on(async {
+        let mut stream = Pending::new(lines("a\nbb\nccc\ndddd"))
+            .filter(|line| line.trim_end().len() != 2)
+            .map(|line| line.trim_end().len())
+            .take(2);
+        let mut lengths = vec![];
+        while let Some(len) = stream.next().await {
+            lengths.push(len);
+        }
+        lengths
+    });
+    assert_eq!(lengths, [1, 3]);
+}
+
+#[test]
+fn test_from_lend_iter() {
+    let mut v = [0, 1, 2, 3];
+    block_on(async {
+        let mut stream = stream_from_lend_iter(v.windows_mut::<2>()).filter(|w| w[0] % 2 == 1);
+        while let Some(w) = stream.next().await {
+            w[1] = 0;
+        }
+    });
+    assert_eq!(v, [0, 1, 0, 3]);
+}
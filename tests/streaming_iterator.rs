@@ -0,0 +1,95 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "streaming-iterator")]
+
+use hrtb_lending_iterator::*;
+use streaming_iterator::{
+    DoubleEndedStreamingIterator, DoubleEndedStreamingIteratorMut, StreamingIterator,
+    StreamingIteratorMut,
+};
+
+#[test]
+fn test_from_streaming_iter() {
+    let mut iter = streaming_iterator::convert([0, 1, 2]).into_lend_iter();
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&2));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_from_streaming_iter_mut() {
+    let mut v = [0, 1, 2, 3];
+    let mut iter = streaming_iterator::windows_mut(&mut v, 2).into_lend_iter_mut();
+    while let Some(window) = iter.next() {
+        window[1] += window[0];
+    }
+    assert_eq!(v, [0, 1, 3, 6]);
+}
+
+/// Lends the numbers from a range through an internal buffer.
+struct Buffered {
+    range: core::ops::Range<usize>,
+    buffer: usize,
+}
+
+impl<'any> LendingIteratorItem<'any> for Buffered {
+    type Type = &'any mut usize;
+}
+
+impl LendingIterator for Buffered {
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        self.buffer = self.range.next()?;
+        Some(&mut self.buffer)
+    }
+}
+
+impl DoubleEndedLendingIterator for Buffered {
+    fn next_back(&mut self) -> Option<Item<'_, Self>> {
+        self.buffer = self.range.next_back()?;
+        Some(&mut self.buffer)
+    }
+}
+
+#[test]
+fn test_into_streaming_iter() {
+    let mut v = [0, 1, 2, 3];
+    let mut iter = v.windows_mut::<2>().into_streaming_iter_mut();
+    assert_eq!(iter.next(), Some(&[0, 1]));
+    iter.get_mut().unwrap()[1] = 5;
+    assert_eq!(iter.next_mut(), Some(&mut [5, 2]));
+    assert_eq!(iter.next(), Some(&[2, 3]));
+    assert_eq!(iter.next(), None);
+    drop(iter);
+    assert_eq!(v, [0, 5, 2, 3]);
+
+    let mut iter = Buffered {
+        range: 0..3,
+        buffer: 0,
+    }
+    .into_streaming_iter_mut();
+    assert_eq!(iter.next(), Some(&0));
+    // The item borrows from the iterator, so it must survive a move.
+    let mut iter = *Box::new(iter);
+    assert_eq!(iter.get(), Some(&0));
+    assert_eq!(iter.next_back_mut(), Some(&mut 2));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_inner().range, 2..2);
+}
+
+#[test]
+fn test_round_trip() {
+    let mut iter = streaming_iterator::convert(["a", "b", "c"])
+        .into_lend_iter()
+        .into_streaming_iter();
+    assert_eq!(iter.next_back(), Some(&"c"));
+    assert_eq!(iter.next(), Some(&"a"));
+    let mut iter = iter.into_inner().into_inner().into_lend_iter();
+    assert_eq!(iter.next(), Some(&"b"));
+    assert_eq!(iter.next(), None);
+}
@@ -17,3 +17,16 @@ fn test_windows_mut() {
     }
     assert_eq!(v[..3], [1, 1, 1]);
 }
+
+#[test]
+fn test_windows_mut_len() {
+    let mut v = [0, 1, 2, 3, 4];
+
+    let mut iter = v.windows_mut::<3>();
+    assert_eq!(iter.len(), 3);
+    iter.next();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.take(1).len(), 1);
+    assert_eq!(v.windows_mut::<6>().len(), 0);
+    assert_eq!(v.windows_mut::<0>().len(), 6);
+}